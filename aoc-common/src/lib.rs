@@ -0,0 +1,210 @@
+//! What every day shares: command-line options, logging, and reading the input.
+//!
+//! A day with nothing special to ask for can just be
+//!
+//! ```no_run
+//! # fn part_1(_: &str) -> usize { 0 }
+//! # fn part_2(_: &str) -> usize { 0 }
+//! fn main() -> anyhow::Result<()> {
+//!     aoc_common::run(part_1, part_2)
+//! }
+//! ```
+//!
+//! Days with extra options can `#[clap(flatten)]` an [`Opts`] into their own.
+//!
+//! Every day also implements [`Solution`] on a `pub struct Day`, and its binary passes
+//! that to [`run`]. The runner at the root of the workspace runs those binaries.
+
+use std::{
+    fmt::{self, Display},
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use log::LevelFilter;
+
+pub use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[derive(Parser, Debug)]
+pub struct Opts {
+    /// Tell me more (or less)
+    #[clap(flatten)]
+    pub verbose: Verbosity<InfoLevel>,
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Input file, or `-` for stdin
+    pub infile: PathBuf,
+}
+
+impl Opts {
+    /// Set up logging at the requested verbosity, then read the input
+    pub fn init(&self) -> Result<String> {
+        init_logger(self.verbose.log_level_filter());
+        read_input(&self.infile)
+    }
+
+    /// Print part `n`'s answer and how long it took, unless `--part` asked for the other one
+    pub fn solve<A: Display>(&self, n: u8, part: impl FnOnce() -> A) {
+        if self.part.is_none_or(|p| p == n) {
+            let start = Instant::now();
+            let answer = part();
+            println!("Part {n} ({:.2?}):\n{answer}", start.elapsed());
+        }
+    }
+}
+
+/// The whole of a file, or of stdin if `path` is `-`
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut out = String::new();
+        io::stdin()
+            .read_to_string(&mut out)
+            .context("couldn't read stdin")?;
+        Ok(out)
+    } else {
+        read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))
+    }
+}
+
+fn builder(level: LevelFilter) -> env_logger::Builder {
+    let mut builder = env_logger::Builder::new();
+    builder
+        .filter_level(level)
+        .format_timestamp(None)
+        .format_module_path(false)
+        .format_target(false)
+        .format_level(false);
+    builder
+}
+
+/// Log messages as they are, without timestamps or levels.
+///
+/// Only the first call has any effect.
+pub fn init_logger(level: LevelFilter) {
+    let _ = builder(level).try_init();
+}
+
+/// Like `init_logger`, but captured by the test harness.
+///
+/// Call it at the start of every test that wants logs; only the first call has any effect.
+pub fn init_test_logger(level: LevelFilter) {
+    let _ = builder(level).is_test(true).try_init();
+}
+
+/// What a part returns, whatever type it worked in
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n as $wide)
+            }
+        })*
+    };
+}
+
+answer_from!(Unsigned, u128: u8, u16, u32, u64, u128, usize);
+answer_from!(Signed, i128: i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// One day's puzzle, with any parameters fixed at their values for the real input
+pub trait Solution {
+    fn part_1(input: &str) -> Answer;
+    fn part_2(input: &str) -> Answer;
+}
+
+/// Parse the standard options, set up logging, read the input, and print the parts asked for
+pub fn run<A, B, P1, P2>(part_1: P1, part_2: P2) -> Result<()>
+where
+    A: Display,
+    B: Display,
+    P1: FnOnce(&str) -> A,
+    P2: FnOnce(&str) -> B,
+{
+    let opts = Opts::parse();
+    let infile = opts.init()?;
+
+    opts.solve(1, || part_1(&infile));
+    opts.solve(2, || part_2(&infile));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options() {
+        let opts = Opts::try_parse_from(["day-00", "-vv", "input.txt"]).unwrap();
+        assert_eq!(opts.infile, PathBuf::from("input.txt"));
+        assert_eq!(opts.verbose.log_level_filter(), LevelFilter::Trace);
+
+        let opts = Opts::try_parse_from(["day-00", "-q", "-"]).unwrap();
+        assert_eq!(opts.verbose.log_level_filter(), LevelFilter::Warn);
+
+        assert!(Opts::try_parse_from(["day-00"]).is_err());
+
+        let opts = Opts::try_parse_from(["day-00", "--part", "2", "-"]).unwrap();
+        assert_eq!(opts.part, Some(2));
+        assert!(Opts::try_parse_from(["day-00", "--part", "3", "-"]).is_err());
+    }
+
+    #[test]
+    fn input() {
+        let path = std::env::temp_dir().join(format!("aoc-common-{}", std::process::id()));
+        std::fs::write(&path, "1 2\n3 4\n").unwrap();
+        assert_eq!(read_input(&path).unwrap(), "1 2\n3 4\n");
+        std::fs::remove_file(&path).unwrap();
+
+        let e = read_input(&path).unwrap_err();
+        assert!(e.to_string().starts_with("couldn't read "));
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(Answer::from(42_usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-7_isize).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+    }
+
+    #[test]
+    fn loggers() {
+        init_test_logger(LevelFilter::Debug);
+        init_test_logger(LevelFilter::Trace);
+        init_logger(LevelFilter::Info);
+    }
+}
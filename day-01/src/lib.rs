@@ -0,0 +1,79 @@
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use nom;
+use regex;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    // read the lists
+
+    let mut left: Vec<usize> = vec![];
+    let mut right: Vec<usize> = vec![];
+
+    for (l, r) in infile.split_whitespace().tuples() {
+        left.push(l.parse::<usize>().unwrap());
+        right.push(r.parse::<usize>().unwrap());
+    }
+
+    // sort the lists
+
+    left.sort();
+    right.sort();
+
+    // sum of pairwise absolute differences
+    let mut tot = 0;
+    for k in 0..left.len() {
+        tot += left[k].abs_diff(right[k])
+    }
+
+    tot
+}
+fn part_2(infile: &str) -> usize {
+    let mut left: Vec<usize> = vec![];
+    let mut right: Vec<usize> = vec![];
+
+    for (l, r) in infile.split_whitespace().tuples() {
+        left.push(l.parse::<usize>().unwrap());
+        right.push(r.parse::<usize>().unwrap());
+    }
+
+    let r_ctr = right.iter().counts();
+
+    let mut tot = 0;
+
+    for k in left {
+        tot += k * r_ctr.get(&k).unwrap_or(&0);
+    }
+
+    tot
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), todo!());
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), todo!());
+    }
+}
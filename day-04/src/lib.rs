@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use mapgrid::{find_word, Coord, Grid, Template};
+use nom;
+use regex;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    // searching for the string XMAS in a 2D grid
+    // can appear in any 8-orientation, backwards, overlapping
+
+    // doesn't appear to wrap around
+
+    // backwards is just another of the 8 directions
+
+    let grid = <HashMap<Coord, char> as Grid>::from_str_with(infile, Some);
+
+    find_word(&grid, "XMAS").len()
+}
+fn part_2(infile: &str) -> usize {
+    // ok now we're looking for MAS in the shape of an X
+    // M.S
+    // .A.
+    // M.S
+    // in any of its four rotations
+
+    let grid = <HashMap<Coord, char> as Grid>::from_str_with(infile, Some);
+    let x_mas = Template::from_str_with("M.S\n.A.\nM.S", |c| (c != '.').then_some(c));
+
+    x_mas.find_symmetric(&grid).len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), 18);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), 9);
+    }
+}
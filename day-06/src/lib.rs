@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Solution};
+use mapgrid::{take_markers, Dir4};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// The map as rows of characters, and the row and column the guard starts at
+fn parse(infile: &str) -> (Vec<Vec<char>>, usize, usize) {
+    let arrow = Dir4::ALL
+        .map(Dir4::to_char)
+        .into_iter()
+        .find(|a| infile.contains(*a))
+        .expect("no guard on the map");
+    // the walk reads the guard's facing from its square, so leave the arrow in place
+    let (text, markers) = take_markers(infile, &[arrow], arrow).unwrap();
+    let [c, r] = markers[&arrow];
+    (
+        text.lines().map(|x| x.chars().collect()).collect(),
+        r as usize,
+        c as usize,
+    )
+}
+
+fn part_1_internal(infile: &str) -> Vec<Vec<char>> {
+    // we get a grid (. for empty space or # for obstacle)
+    // with ^V<> representing a guard position and direction
+    // guard moves forward until hitting an obstacle, then turns right
+    // eventually guard will walk out of the grid
+    // replace positions with Xs
+
+    let (mut grid, mut r, mut c) = parse(infile);
+
+    // println!("Guard starts at ({r}, {c})");
+
+    'fill: loop {
+        let [dc, dr] = Dir4::try_from(grid[r][c]).unwrap().to_coord();
+
+        let fr = r as isize + dr;
+        let fc = c as isize + dc;
+
+        if fr < 0 || fc < 0 {
+            grid[r][c] = 'X';
+            break 'fill;
+        }
+
+        let fr = fr as usize;
+        let fc = fc as usize;
+
+        if fr >= grid.len() || fc >= grid[fr].len() {
+            grid[r][c] = 'X';
+            break 'fill;
+        }
+
+        // test faced direction
+        let turn = match grid[fr][fc] {
+            '#' => true,
+            _ => false,
+        };
+
+        if turn {
+            grid[r][c] = Dir4::try_from(grid[r][c]).unwrap().turn_right().to_char();
+        } else {
+            grid[fr][fc] = grid[r][c];
+            grid[r][c] = 'X';
+            r = fr;
+            c = fc;
+        }
+    }
+
+    grid
+}
+
+fn part_1(infile: &str) -> usize {
+    part_1_internal(infile)
+        .iter()
+        .flatten()
+        .filter(|x| **x == 'X')
+        .count()
+}
+
+fn part_2(infile: &str) -> usize {
+    // hoo boy, now we need to place an obstacle so as to cause a loop
+    // we have (checks notes) about five thousand options so this is technically brute-forceable (17k total map size)
+
+    let part_1_grid = part_1_internal(infile);
+    // println!(
+    //     "{}\n",
+    //     part_1_grid
+    //         .iter()
+    //         .map(|s| s.iter().collect::<String>())
+    //         .join("\n")
+    // );
+
+    let (grid_orig, guard_r, guard_c) = parse(infile);
+
+    // println!("Guard starts at ({guard_r}, {guard_c})");
+
+    let mut placeables = vec![];
+    let options: Vec<(usize, usize)> = part_1_grid
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.iter().enumerate().filter_map(move |(c, x)| {
+                if *x == 'X' {
+                    Some((r.clone(), c, *x))
+                } else {
+                    None
+                }
+            })
+        })
+        .map(|(r, c, _)| (r, c))
+        .collect();
+
+    // println!("{} options", options.len());
+
+    'testing: for (obs_r, obs_c) in options {
+        if grid_orig[obs_r][obs_c] != '.' {
+            continue 'testing;
+        }
+
+        let mut r = guard_r;
+        let mut c = guard_c;
+
+        let mut grid = grid_orig.clone();
+
+        grid[obs_r][obs_c] = '#';
+
+        let mut turns: HashSet<(usize, usize, char)> = HashSet::new();
+
+        'fill: loop {
+            let [dc, dr] = Dir4::try_from(grid[r][c]).unwrap().to_coord();
+
+            let fr = r as isize + dr;
+            let fc = c as isize + dc;
+
+            if fr < 0 || fc < 0 {
+                break 'fill;
+            }
+
+            let fr = fr as usize;
+            let fc = fc as usize;
+
+            if fr >= grid.len() || fc >= grid[fr].len() {
+                break 'fill;
+            }
+
+            // test faced direction
+            let turn = match grid[fr][fc] {
+                '#' => true,
+                _ => false,
+            };
+
+            if turn {
+                if turns.contains(&(r, c, grid[r][c])) {
+                    placeables.push((obs_r, obs_c));
+                    break 'fill;
+                } else {
+                    turns.insert((r, c, grid[r][c]));
+                }
+
+                grid[r][c] = Dir4::try_from(grid[r][c]).unwrap().turn_right().to_char();
+            } else {
+                grid[fr][fc] = grid[r][c];
+                grid[r][c] = 'X';
+                r = fr;
+                c = fc;
+            }
+            // continue 'fill
+        }
+    }
+
+    // println!("{placeables:?}");
+
+    placeables.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), 41);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), 6);
+    }
+}
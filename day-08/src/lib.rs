@@ -0,0 +1,175 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeBounds,
+};
+
+use aoc_common::{Answer, Solution};
+use itertools::{Itertools, Unique};
+use mapgrid::{ray, Bounded, Coord, Grid, Vec2};
+use nom;
+use regex;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    // Nodes marked by [0-9][A-Z][a-z]
+    // If two nodes of the same type are at
+    // (X, Y) and (X + i, Y + j)
+    // Then antinodes occur at
+    //   (X + 2i, Y + 2j)
+    //   (X - i, Y - j)
+    // within the bounds of the map of course
+
+    let grid = Bounded::<HashMap<Coord, char>>::from_str_with(infile, |x| {
+        if x.is_digit(10) || x.is_ascii_alphabetic() {
+            Some(x)
+        } else {
+            None
+        }
+    });
+
+    // println!("{}", grid.visualise());
+
+    // need a reverse LUT
+
+    let bounds = grid.bounds();
+
+    let mut lut: HashMap<char, Vec<Coord>> = HashMap::new();
+
+    for (coords, cha) in grid.into_inner() {
+        lut.entry(cha).or_insert(vec![]).push(coords);
+    }
+
+    let mut outpos: HashMap<Coord, char> = HashMap::new();
+
+    for (cha, coords) in lut {
+        for i in 0..coords.len() {
+            for j in 0..coords.len() {
+                if i == j {
+                    continue;
+                }
+
+                let a = Vec2::from(coords[i]);
+                let b = Vec2::from(coords[j]);
+
+                let n = a + (a - b);
+
+                // println!("{cha}: {a} & {b} => {n}");
+
+                if bounds[0].contains(&n.x) && bounds[1].contains(&n.y) {
+                    outpos.insert(n.into(), cha);
+                }
+            }
+        }
+    }
+
+    // println!(
+    //     "{}",
+    //     <HashMap<Coord, char> as Grid<char>>::visualise(&outpos)
+    // );
+
+    // 290 was too high, probably because not unique
+    // actually, because I had infile.len() rather than infile.lines().count()
+    outpos.iter().map(|(k, _)| k).unique().count()
+}
+
+fn part_2(infile: &str) -> usize {
+    // Nodes marked by [0-9][A-Z][a-z]
+    // If two nodes of the same type are at
+    // (X, Y) and (X + i, Y + j)
+    // Then antinodes occur at
+    //   (X + 2i, Y + 2j)
+    //   (X - i, Y - j)
+    // within the bounds of the map of course
+    // now for part two they occur generally at (X + ki, Y + kj) for integer k
+
+    let grid = Bounded::<HashMap<Coord, char>>::from_str_with(infile, |x| {
+        if x.is_digit(10) || x.is_ascii_alphabetic() {
+            Some(x)
+        } else {
+            None
+        }
+    });
+
+    // println!("{}", grid.visualise());
+
+    // need a reverse LUT
+
+    let bounds = grid.bounds();
+
+    let mut lut: HashMap<char, Vec<Coord>> = HashMap::new();
+
+    for (coords, cha) in grid.into_inner() {
+        lut.entry(cha).or_insert(vec![]).push(coords);
+    }
+
+    let mut outpos: HashMap<Coord, char> = HashMap::new();
+
+    for (cha, coords) in lut {
+        for i in 0..coords.len() {
+            for j in 0..coords.len() {
+                if i == j {
+                    continue;
+                }
+
+                let a = Vec2::from(coords[i]);
+                let b = Vec2::from(coords[j]);
+
+                let d = a - b;
+
+                for n in ray(a.into(), d.into(), bounds.clone()) {
+                    // println!("{cha}: {a} & {b} => {n:?}");
+
+                    outpos.insert(n, cha);
+                }
+            }
+        }
+    }
+
+    // println!(
+    //     "{}",
+    //     <HashMap<Coord, char> as Grid<char>>::visualise(&outpos)
+    // );
+
+    // worked first try!
+    outpos.iter().map(|(k, _)| k).unique().count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#.";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), 14);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), 34);
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use mapgrid::{label_regions, Connectivity, Coord, Grid};
+use nom;
+use regex;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    let grid: HashMap<Coord, char> =
+        <HashMap<Coord, char> as Grid<char>>::from_str_with(infile, |x| Some(x));
+
+    // not sure if we can have diagonal neighbours, omit for now
+    label_regions(&grid, Connectivity::Four)
+        .regions
+        .iter()
+        .map(|r| r.area() * r.perimeter())
+        .sum()
+}
+fn part_2(infile: &str) -> usize {
+    // now we want the number of sides of regions however long they may be!
+    let grid: HashMap<Coord, char> =
+        <HashMap<Coord, char> as Grid<char>>::from_str_with(infile, |x| Some(x));
+
+    // every side of a region starts and ends at a corner, so count those instead
+    label_regions(&grid, Connectivity::Four)
+        .regions
+        .iter()
+        .map(|r| r.area() * r.sides())
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), 1930);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), 1206);
+    }
+}
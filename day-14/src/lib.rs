@@ -0,0 +1,232 @@
+use std::{fs::write, path::Path};
+
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use log::{debug, warn};
+use mapgrid::*;
+use rayon::{
+    self,
+    iter::{IntoParallelIterator, ParallelIterator},
+};
+use regex::Regex;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input, 101, 103).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input, 101, 103, None).into()
+    }
+}
+
+pub fn part_1(infile: &str, width: isize, height: isize) -> usize {
+    // world wrap
+
+    let re = Regex::new(r#"-?\d+"#).unwrap();
+
+    let robots: Vec<(Coord, Coord)> = re
+        .find_iter(infile)
+        .filter_map(|x| x.as_str().parse::<isize>().ok())
+        .tuples()
+        .map(|(px, py, vx, vy)| ([px, py], [vx, vy]))
+        .collect();
+
+    // for r in &robots {
+    //     println!("{r:?}");
+    // }
+
+    let torus = Torus::new(width, height);
+
+    let after_100s: Vec<(Coord, Coord)> = robots
+        .into_iter()
+        .map(|(p, v)| (torus.advance(p, v, 100), v))
+        .collect();
+
+    let positions_count = after_100s.iter().map(|(coord, _)| coord).counts();
+
+    let mut quads = [0, 0, 0, 0];
+
+    for ([px, py], count) in positions_count {
+        let w = width / 2;
+        let h = height / 2;
+        let qx: usize = if *px > w {
+            1
+        } else if *px < w {
+            0
+        } else {
+            continue;
+        };
+        let qy: usize = if *py > h {
+            2
+        } else if *py < h {
+            0
+        } else {
+            continue;
+        };
+
+        // println!("{} robot at [{}, {}] in quad {}", count, px, py, qx + qy);
+
+        quads[qx + qy] += count;
+    }
+    quads.iter().fold(1, |acc, x| acc * x)
+}
+
+pub fn part_2(infile: &str, width: isize, height: isize, frames: Option<&Path>) -> isize {
+    // world wrap
+
+    let re = Regex::new(r#"-?\d+"#).unwrap();
+
+    let robots_orig: Vec<(Coord, Coord)> = re
+        .find_iter(infile)
+        .filter_map(|x| x.as_str().parse::<isize>().ok())
+        .tuples()
+        .map(|(px, py, vx, vy)| ([px, py], [vx, vy]))
+        .collect();
+
+    // for r in &robots {
+    //     println!("{r:?}");
+    // }
+
+    let torus = Torus::new(width, height);
+    let seconds_tot = width * height;
+
+    if let Some(seconds) = (0..seconds_tot)
+        .into_par_iter()
+        .filter_map(|s| p2_helper(&robots_orig, s, torus, frames))
+        .min()
+    {
+        return seconds;
+    } else {
+        warn!("Could not find tree after {seconds_tot} seconds; here's the grid on the last iteration");
+
+        let mut grid = BitGrid::new(width as usize, height as usize);
+        grid.extend(
+            robots_orig
+                .iter()
+                .map(|(p, v)| torus.advance(*p, *v, seconds_tot)),
+        );
+
+        warn!("{}", torus.renderer(&grid, |_| '#').plain());
+        return -1;
+    }
+}
+
+fn p2_helper(
+    robots: &[(Coord, Coord)],
+    seconds: isize,
+    torus: Torus,
+    frames: Option<&Path>,
+) -> Option<isize> {
+    let mut grid = BitGrid::new(torus.width() as usize, torus.height() as usize);
+    grid.extend(robots.iter().map(|(p, v)| torus.advance(*p, *v, seconds)));
+
+    // we're looking for a picture of a christmas tree, which if rumour is to be believed means a block similar to the below
+    /*
+    ....#....
+    ...###...
+    ..#####..
+    .#######.
+    */
+
+    // we have 500 robots and a 101x103 grid
+    // the triangle numbers approaching 500 are:
+    // 253 (22nd), 276 (23rd), 300 (24th), 325 (25th), 351 (26th), 378 (27th),
+    // 406 (28th), 435 (29th), 465 (30th) and 496 (31st)
+
+    // so we should search for a row containing at least 23 contiguous occupied spaces ("most of the robots" and odd)
+    // if we find this then we check to see if the row above is of pattern .#####################. (21 contig with spaces at edge)
+    // if it is we've probably found it
+
+    // Looking at some spoilers it's not quite that easy
+    // but the approach of "find a run within a row, then check if there's a run in the row above" seems OK
+
+    for y in 0..torus.height() {
+        if grid.row_count(y) < 7 {
+            // can't possibly have a long enough run
+            continue;
+        }
+        let mut run = 0_isize;
+        for x in 0..torus.width() {
+            if grid.contains(&[x, y]) {
+                run += 1;
+                if run >= 7_isize {
+                    // check for run in row above
+                    let yy = y - 1;
+                    let mut above = 0;
+                    for xx in (x - run)..=x {
+                        if grid.contains(&[xx, yy]) {
+                            above += 1;
+                        }
+                    }
+                    if above == run - 2 {
+                        debug!("Possible Christmas Tree after {seconds} seconds");
+                        debug!("{}", torus.renderer(&grid, |_| '#').plain());
+                        if let Some(dir) = frames {
+                            save_frame(dir, seconds, &grid);
+                        }
+                        return Some(seconds);
+                    }
+                }
+            } else {
+                run = 0;
+            }
+        }
+    }
+    None
+}
+
+/// Write out the whole torus, robots in black
+fn save_frame(dir: &Path, seconds: isize, robots: &BitGrid) {
+    let path = dir.join(format!("{seconds:05}.pbm"));
+    if let Err(e) = write(&path, to_pbm(robots, |v| v.is_some())) {
+        warn!("couldn't write {}: {e}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    const PART_2_DEBUG: &str = r"p=3,0 v=0,0
+p=2,1 v=0,0
+p=3,1 v=0,0
+p=4,1 v=0,0
+p=1,2 v=0,0
+p=2,2 v=0,0
+p=3,2 v=0,0
+p=4,2 v=0,0
+p=5,2 v=0,0
+p=0,3 v=0,0
+p=1,3 v=0,0
+p=2,3 v=0,0
+p=3,3 v=0,0
+p=4,3 v=0,0
+p=5,3 v=0,0
+p=6,3 v=0,0";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1, 11, 7), 12);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(PART_2_DEBUG, 7, 4, None), 0);
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use log::{debug, log_enabled, trace, Level};
+use mapgrid::*;
+use strum::{self, Display, EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input, None).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[derive(Debug, Display, EnumString, EnumIter, IntoStaticStr, PartialEq, Eq, Hash, Clone, Copy)]
+enum Entity {
+    #[strum(serialize = "#")]
+    Wall,
+    #[strum(serialize = "@")]
+    Robot,
+    #[strum(serialize = "O")]
+    Box,
+    /// conventionally, positioned by its left edge
+    /// but taking up TWO positions on x axis
+    #[strum(serialize = "[]")]
+    WideBox,
+}
+
+/// Every entity's token, as spelled by its `strum` serialisation, plus `.` for empty space
+fn tiles() -> Vec<(&'static str, Option<Entity>)> {
+    Entity::iter()
+        .map(|e| (e.into(), Some(e)))
+        .chain([(".", None)])
+        .collect()
+}
+
+/// The map without the robot, and where the robot is.
+///
+/// Panics, saying where, on anything other than `#@O.`, or if there isn't exactly one robot.
+fn parse_map(grid_raw: &str) -> (HashMap<Coord, Entity>, Coord) {
+    let (grid_raw, markers) = take_markers(grid_raw, &['@'], '.').unwrap();
+    let grid = HashMap::try_from_str_with(&grid_raw, |c| match c {
+        '.' => Ok(None),
+        c => Entity::try_from(c.to_string().as_str()).map(Some),
+    })
+    .unwrap_or_else(|e| panic!("bad map: {e}"));
+    (grid, markers[&'@'])
+}
+
+fn move_to_dir(m: char) -> Option<Coord> {
+    Dir4::try_from(m).ok().map(Dir4::to_coord)
+}
+
+pub fn part_1(infile: &str, mut recorder: Option<&mut Recorder>) -> isize {
+    let (grid_raw, moves) = infile.split("\n\n").collect_tuple().unwrap();
+
+    let (grid, mut robot) = parse_map(grid_raw);
+
+    debug!("{}", Renderer::new(&grid).plain());
+
+    let walls: HashSet<Coord> = grid
+        .iter()
+        .filter(|(_, v)| **v == Entity::Wall)
+        .map(|(k, _)| k)
+        .cloned()
+        .collect();
+    let mut boxes: HashSet<Coord> = grid
+        .iter()
+        .filter(|(_, v)| **v == Entity::Box)
+        .map(|(k, _)| k)
+        .cloned()
+        .collect();
+
+    drop(grid);
+
+    for dir in moves.chars().filter_map(move_to_dir) {
+        let mut test = robot;
+        loop {
+            test = add(test, dir);
+
+            if walls.contains(&test) {
+                // no movement in this direction
+                break;
+            } else if boxes.contains(&test) {
+                continue;
+            } else {
+                // empty space found
+                let newrobot = add(robot, dir);
+                boxes.insert(test); // we "pushed" a line of boxes
+                boxes.remove(&newrobot);
+                robot = newrobot;
+                break;
+            }
+        }
+        if let Some(rec) = recorder.as_mut() {
+            // frames are only ever played back on a terminal
+            rec.capture(
+                &Renderer::with_glyphs(&walls, |_| '#')
+                    .layer(
+                        Layer::new(boxes.iter().copied())
+                            .glyph('O')
+                            .colour(Colour::Yellow),
+                    )
+                    .layer(Layer::new([robot]).glyph('@').colour(Colour::Red))
+                    .colour(true),
+            );
+        }
+    }
+
+    boxes.iter().map(|[x, y]| x + y * 100).sum::<isize>()
+}
+pub fn part_2(infile: &str) -> isize {
+    let (grid_raw, moves) = infile.split("\n\n").collect_tuple().unwrap();
+
+    let wide = expand(
+        grid_raw,
+        &[('#', "##"), ('O', "[]"), ('.', ".."), ('@', "@.")],
+    );
+    let (wide, markers) = take_markers(&wide, &['@'], '.').unwrap();
+    let mut robot = markers[&'@'];
+    let grid: HashMap<Coord, Entity> =
+        parse_tokens(&wide, &tiles()).unwrap_or_else(|e| panic!("bad map: {e}"));
+
+    debug!("{}", render_tokens(&grid, &tiles()));
+    debug!("{:?}", grid.values().counts());
+
+    let walls: HashSet<Coord> = grid
+        .iter()
+        .filter(|(_, v)| **v == Entity::Wall)
+        .map(|(k, _)| k)
+        .cloned()
+        .collect();
+    let mut wide_boxes: HashSet<Coord> = grid
+        .iter()
+        .filter(|(_, v)| **v == Entity::WideBox)
+        .map(|(k, _)| k)
+        .cloned()
+        .collect();
+
+    drop(grid);
+
+    // previously each box could only push one other box
+    // but now we can push two
+    // this means the following is possible:
+
+    /*
+        [][][][][]
+         [][][][]
+          [][][]
+           [][]
+            []
+            @
+    */
+
+    for (i, dir) in moves.chars().filter_map(move_to_dir).enumerate() {
+        let mut moving = vec![];
+        let mut queue = vec![robot];
+        let mut wall_found = false;
+        while let Some(next) = queue.pop() {
+            // if we're the robot, we only need to test whether a wall is directly blocking us
+            // or if we're pushing on LHS or RHS of a widebox
+
+            // if we're a widebox (not the robot)
+            // we need to test whether a wall is blocking our LHS or RHS
+            // and our LHS could be pushing on an RHS or an LHS, and our RHS could be pushing on an LHS tooi
+
+            let is_robot = next == robot;
+
+            let test = add(next, dir);
+
+            if walls.contains(&test) || (!is_robot && walls.contains(&add(test, [1, 0]))) {
+                // no movement in this direction
+                moving.clear();
+                queue.clear();
+                wall_found = true;
+                trace!("{i}: {next:?} hit wall");
+                break;
+            }
+
+            for d in [[-1, 0], [0, 0], [1, 0]] {
+                let side = add(test, d);
+                if wide_boxes.contains(&side) && !moving.contains(&side) && !queue.contains(&side) {
+                    if d == [1, 0] && is_robot {
+                        continue;
+                    }
+                    queue.push(side);
+                    moving.push(side);
+                    // else: empty space, continue
+                    trace!(
+                        "{i}: {next:?} found widebox at {side:?}, queue now {} deep",
+                        queue.len()
+                    );
+                }
+            }
+        }
+        if !wall_found {
+            // once we have dealt with everything
+
+            for m in &moving {
+                wide_boxes.remove(m);
+            }
+            for m in moving {
+                wide_boxes.insert(add(m, dir));
+            }
+            robot = add(robot, dir);
+        }
+        if log_enabled!(Level::Trace) {
+            let mut snapshot: HashMap<Coord, Entity> =
+                walls.iter().map(|k| (*k, Entity::Wall)).collect();
+            snapshot.extend(wide_boxes.iter().map(|k| (*k, Entity::WideBox)));
+            snapshot.insert(robot, Entity::Robot);
+            trace!("{i} {dir:?}:\n{}", render_tokens(&snapshot, &tiles()));
+        }
+    }
+
+    wide_boxes.iter().map(|[x, y]| x + y * 100).sum::<isize>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const EXAMPLE_1_END: &str = r"########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########";
+
+    const LARGER_EG: &str = r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const SMALLER_2: &str = r"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1, None), 2028);
+    }
+    #[test]
+    fn part_1_final_state() {
+        let mut rec = Recorder::new();
+        part_1(EXAMPLE_1, Some(&mut rec));
+
+        let got: HashMap<Coord, char> = rec
+            .frames()
+            .last()
+            .unwrap()
+            .pixels()
+            .iter()
+            .filter(|(_, p)| p.glyph != '.')
+            .map(|(k, p)| (k, p.glyph))
+            .collect();
+        let want =
+            HashMap::<Coord, char>::from_str_with(EXAMPLE_1_END, |c| (c != '.').then_some(c));
+        assert_grid_eq!(got, want);
+    }
+
+    #[test]
+    fn part_1_large() {
+        assert_eq!(part_1(LARGER_EG, None), 10092);
+    }
+
+    #[test]
+    fn part_2_small() {
+        assert_eq!(part_2(SMALLER_2), 618);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(LARGER_EG), 9021);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Solution};
+use log::debug;
+use mapgrid::*;
+use nom;
+use regex;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// The ways we can turn from any given facing
+fn turns() -> [fn(Dir4) -> Dir4; 3] {
+    [Dir4::turn_left, Dir4::turn_right, |d| d]
+}
+
+fn part_1(infile: &str) -> usize {
+    let (grid, markers): (HashMap<Coord, char>, _) =
+        parse_with_markers(infile, &['S', 'E'], '.', Some).unwrap();
+    let (start_pos, end_pos) = (markers[&'S'], markers[&'E']);
+
+    debug!(
+        "{}",
+        Renderer::new(&grid)
+            .layer(Layer::glyphs(markers.iter().map(|(m, k)| (*k, *m))))
+            .plain()
+    );
+
+    let turns = turns().into_iter().zip([1001, 1001, 1]);
+
+    debug!("start: {start_pos:?}\tend: {end_pos:?}");
+
+    // state is (position, facing); turning and then stepping costs 1001
+    let result = search::dijkstra((start_pos, Dir4::East), |&(pos, facing)| {
+        turns
+            .clone()
+            .map(move |(turn, pts)| (add(pos, turn(facing).to_coord()), turn(facing), pts))
+            .filter(|(newpos, _, _)| grid.get(newpos).unwrap_or(&'#') != &'#')
+            .map(|(newpos, newdir, pts)| ((newpos, newdir), pts))
+    });
+
+    let Some(end) = Dir4::ALL
+        .into_iter()
+        .map(|d| (end_pos, d))
+        .filter(|s| result.dist.contains_key(s))
+        .min_by_key(|s| result.dist[s])
+    else {
+        return usize::MAX;
+    };
+
+    let path = result.path_to(&end).unwrap();
+    let path = Layer::glyphs(
+        path.into_iter()
+            .map(|(pos, facing)| (pos, facing.to_char())),
+    );
+
+    debug!(
+        "{}",
+        Renderer::new(&grid)
+            .base_colour(Colour::Grey)
+            .layer(path.colour(Colour::Yellow))
+            .plain()
+    );
+
+    result.dist[&end]
+}
+// now we have to keep track of all of the best paths through the maze
+
+fn part_2(infile: &str) -> usize {
+    let (grid, markers): (HashMap<Coord, char>, _) =
+        parse_with_markers(infile, &['S', 'E'], '.', Some).unwrap();
+    let (start_pos, end_pos) = (markers[&'S'], markers[&'E']);
+
+    debug!("start: {start_pos:?}\tend: {end_pos:?}");
+
+    // this time, turning is done on the spot and costs 1000
+    let grid = &grid;
+    let result = search::dijkstra_all((start_pos, Dir4::East), |&(pos, facing)| {
+        turns()
+            .into_iter()
+            .zip([1000, 1000, 1])
+            .filter_map(move |(turn, pts)| {
+                let newdir = turn(facing);
+                let newpos = if newdir == facing {
+                    add(pos, facing.to_coord())
+                } else {
+                    pos
+                };
+                (grid.get(&newpos).unwrap_or(&'#') != &'#').then_some(((newpos, newdir), pts))
+            })
+    });
+
+    let tiles: HashSet<Coord> = result
+        .on_optimal_paths(Dir4::ALL.map(|d| (end_pos, d)))
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect();
+
+    debug!(
+        "{}",
+        Renderer::new(grid)
+            .base_colour(Colour::Grey)
+            .layer(
+                Layer::new(tiles.iter().copied())
+                    .glyph('O')
+                    .colour(Colour::Green)
+            )
+            .plain()
+    );
+
+    tiles.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const EXAMPLE_2: &str = r"#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn directionality() {
+        let [left, right, fwd] = turns();
+        assert_eq!(left(Dir4::North), Dir4::West);
+        assert_eq!(right(Dir4::North), Dir4::East);
+        assert_eq!(fwd(Dir4::North), Dir4::North);
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1), 7036);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1), 45);
+    }
+    #[test]
+    fn part_2_example_2() {
+        assert_eq!(part_2(EXAMPLE_2), 64);
+    }
+}
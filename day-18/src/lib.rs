@@ -0,0 +1,148 @@
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use mapgrid::*;
+use nom;
+use regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input, 1024, 70).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input, 1024, 70).into()
+    }
+}
+
+fn part_1(infile: &str, falls: usize, max: isize) -> usize {
+    let walls: HashSet<Coord> = infile
+        .lines()
+        .take(falls)
+        .flat_map(|s| s.split(","))
+        .map(|s| s.parse().unwrap())
+        .tuples()
+        .map(|(x, y)| [x, y])
+        .collect();
+
+    // println!("{}", <HashSet<Coord> as Grid>::visualise(&walls));
+
+    let mut combine = HashMap::new();
+
+    let open: HashSet<Coord> = (0..=max)
+        .cartesian_product(0..=max)
+        .map(|(x, y)| [x, y])
+        .filter(|k| !walls.contains(k))
+        .collect();
+
+    let path = simple_maze(&open, [0, 0], [max, max]);
+
+    for k in walls {
+        combine.insert(k, '#');
+    }
+
+    for k in &path {
+        combine.insert(k.clone(), 'O');
+    }
+    /*
+    println!(
+        "{}len={}\n",
+        <HashMap<Coord, char> as Grid>::visualise(&combine),
+        path.len()
+    );
+    */
+
+    path.len()
+}
+
+fn part_2(infile: &str, falls: usize, max: isize) -> &str {
+    let bytes: Vec<Coord> = infile
+        .lines()
+        .flat_map(|s| s.split(","))
+        .map(|s| s.parse().unwrap())
+        .tuples()
+        .map(|(x, y)| [x, y])
+        .collect();
+
+    // work backwards from every byte having fallen, rather than searching again after each one.
+    // Bisecting with `monotone` also works (see `part_2_bisect`), but costs a BFS per probe.
+    let i = first_blocking(
+        [0..=max, 0..=max],
+        &bytes,
+        [0, 0],
+        [max, max],
+        Connectivity::Four,
+    )
+    .unwrap();
+    debug_assert!(i >= falls, "part 1 says the first {falls} leave a way out");
+
+    infile.lines().nth(i).unwrap()
+}
+
+/// Shortest path through the `open` cells, not counting `start`
+fn simple_maze(open: &HashSet<Coord>, start: Coord, end: Coord) -> Vec<Coord> {
+    let result = search::bfs(start, |&spot| open.neighbours4(spot).map(|(next, _)| next));
+
+    // we don't count the start position
+    result
+        .path_to(&end)
+        .map(|path| path[1..].to_vec())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(part_1(EXAMPLE_1, 12, 6), 22);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(part_2(EXAMPLE_1, 12, 6), "6,1");
+    }
+
+    #[test]
+    fn part_2_bisect() {
+        // the old way: the first number of fallen bytes with no path out
+        let lines: Vec<&str> = EXAMPLE_1.lines().collect();
+        let blocked = monotone::Search::new()
+            .verify(lines.len())
+            .first(0..lines.len() + 1, |n| {
+                (part_1(EXAMPLE_1, n, 6) == 0).then_some(())
+            })
+            .unwrap();
+        assert_eq!(lines[blocked.at - 1], part_2(EXAMPLE_1, 12, 6));
+    }
+}
@@ -0,0 +1,174 @@
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use log::{debug, info, trace, warn};
+use mapgrid::*;
+use nom;
+use regex;
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::AddAssign;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    /*!
+
+        input:
+
+        1. sequence of sequences of atoms (towels with stripes)
+        2. sequence of sequences of atoms (orders of stripes)
+
+    No limit on how many towels you can use
+
+
+    This feels DFS-y? iterate through each choice, if you can match a prefix then recurse
+
+    */
+
+    let mut lines = infile.lines().filter(|s| s.len() > 0);
+
+    let towels: Vec<String> = lines
+        .next()
+        .unwrap()
+        .split(", ")
+        .map(str::to_owned)
+        .collect();
+
+    debug!("{towels:?}");
+
+    let mut memo = HashMap::new();
+
+    let mut total = 0;
+    for (i, d) in lines.enumerate() {
+        if part_1_helper(&towels, d, &mut memo) {
+            total += 1;
+        }
+    }
+    total
+}
+
+/// For each towel, check if
+fn part_1_helper<'a>(
+    towels: &[String],
+    design: &'a str,
+    memo: &mut HashMap<&'a str, bool>,
+) -> bool {
+    trace!("{design}");
+    if memo.contains_key(design) {
+        return *memo.get(design).unwrap();
+    }
+    for t in towels {
+        if design == t {
+            memo.insert(design, true);
+            return true;
+        } else if design.starts_with(t) {
+            if part_1_helper(towels, &design[t.len()..], memo) {
+                memo.insert(design, true);
+                return true;
+            }
+        }
+    }
+    memo.insert(design, false);
+    return false;
+}
+
+fn part_2(infile: &str) -> usize {
+    /*!
+
+        input:
+
+        1. sequence of sequences of atoms (towels with stripes)
+        2. sequence of sequences of atoms (orders of stripes)
+
+    No limit on how many towels you can use
+
+
+    This feels DFS-y? iterate through each choice, if you can match a prefix then recurse
+
+    */
+
+    let mut lines = infile.lines().filter(|s| s.len() > 0);
+
+    let towels: Vec<String> = lines
+        .next()
+        .unwrap()
+        .split(", ")
+        .map(str::to_owned)
+        .collect();
+
+    debug!("{towels:?}");
+
+    let mut memo = HashMap::new();
+
+    let mut total = 0;
+    for (i, d) in lines.enumerate() {
+        let rez = part_2_helper(&towels, d, &mut memo);
+
+        debug!("{i}: +{rez}");
+
+        total += rez;
+    }
+    debug!("{memo:?}");
+
+    total
+}
+
+/// For each towel, check if
+fn part_2_helper<'a>(
+    towels: &[String],
+    design: &'a str,
+    memo: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if memo.contains_key(design) {
+        return *memo.get(design).unwrap();
+    }
+    let mut arrangements = 0;
+    for t in towels {
+        if design == t {
+            arrangements += 1;
+        } else if design.starts_with(t) {
+            arrangements += part_2_helper(towels, &design[t.len()..], memo);
+        }
+    }
+    memo.insert(design, arrangements);
+    return arrangements;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn part_1_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Trace);
+        assert_eq!(part_1(EXAMPLE_1), 6);
+    }
+
+    #[test]
+    fn part_2_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Trace);
+        assert_eq!(part_2(EXAMPLE_1), 16);
+    }
+}
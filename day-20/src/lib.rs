@@ -0,0 +1,171 @@
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use log::{debug, trace};
+use mapgrid::*;
+use nom;
+use regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::RangeBounds;
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// distance to the goal
+fn flood_fill(grid: &HashMap<Coord, char>, goal: Coord) -> HashMap<Coord, usize> {
+    search::bfs(goal, move |&spot| {
+        grid.neighbours4(spot)
+            .filter(|(_, c)| **c != '#')
+            .map(|(next, _)| next)
+    })
+    .dist
+}
+
+fn cheat_2_moves() -> HashSet<Coord> {
+    let mut out = HashSet::new();
+    for i in Dir4::ALL.map(Dir4::to_coord) {
+        for j in Dir4::ALL.map(Dir4::to_coord) {
+            out.insert(add(i, j));
+        }
+    }
+    out
+}
+
+fn part_1(infile: &str) -> usize {
+    // we're running a maze ([S]tart, [E]nd, `.` path, `#` wall)
+    // 4-neighbours
+    // exactly once in the run, we may glitch through walls for two moves
+    // we want to know how many
+
+    let (grid, markers): (HashMap<Coord, char>, _) =
+        parse_with_markers(infile, &['S', 'E'], '.', Some).unwrap();
+    let (start_pos, end_pos) = (markers[&'S'], markers[&'E']);
+
+    // first get the normal time
+
+    let dists = flood_fill(&grid, end_pos);
+
+    let no_cheats_time = dists.get(&start_pos).unwrap();
+
+    trace!("No cheating time: {no_cheats_time}");
+    trace!("{dists:?}");
+
+    let moves = cheat_2_moves();
+
+    trace!("2-move cheats: {moves:?}");
+
+    let mut cheats: BTreeMap<(Coord, Coord), usize> = BTreeMap::new();
+
+    for (pos, dist) in &dists {
+        for m in &moves {
+            let cheat = add(*pos, *m);
+
+            if let Some(cheat_dist) = dists.get(&cheat) {
+                if cheat_dist + 2 < *dist {
+                    let saving = (dist - cheat_dist) - 2;
+                    trace!("Cheat: start @{pos:?} ${dist} finish @{cheat:?} ${cheat_dist} saving ${saving}");
+                    cheats.insert((*pos, cheat), saving);
+                }
+            }
+        }
+    }
+
+    trace!("All Cheats:\n{cheats:?}");
+    trace!("Grouped by savings:\n{:?}", cheats.values().counts());
+
+    cheats.iter().filter(|(_, v)| **v >= 100).count()
+}
+
+/// Cheat by up to 20 moves (but at least two)
+fn cheat_20_moves() -> impl Iterator<Item = Coord> {
+    manhattan_ball([0, 0], 20).filter(|m| Vec2::from(*m).manhattan() >= 2)
+}
+
+fn part_2(infile: &str) -> usize {
+    // we're running a maze ([S]tart, [E]nd, `.` path, `#` wall)
+    // 4-neighbours
+    // exactly once in the run, we may glitch through walls for two moves
+    // we want to know how many
+
+    let (grid, markers): (HashMap<Coord, char>, _) =
+        parse_with_markers(infile, &['S', 'E'], '.', Some).unwrap();
+    let (start_pos, end_pos) = (markers[&'S'], markers[&'E']);
+
+    // first get the normal time
+
+    let dists = flood_fill(&grid, end_pos);
+
+    let no_cheats_time = dists.get(&start_pos).unwrap();
+
+    trace!("No cheating time: {no_cheats_time}");
+    trace!("{dists:?}");
+
+    trace!("20-move cheats: {:?}", cheat_20_moves().collect_vec());
+
+    let mut cheats: BTreeMap<(Coord, Coord), usize> = BTreeMap::new();
+
+    for (pos, dist) in &dists {
+        for m in cheat_20_moves() {
+            let cheat = add(*pos, m);
+
+            if let Some(cheat_dist) = dists.get(&cheat) {
+                let cost = Vec2::from(m).manhattan() as usize;
+                if cheat_dist + cost < *dist {
+                    let saving = (dist - cheat_dist) - cost;
+                    trace!("Cheat: start @{pos:?} ${dist} finish @{cheat:?} ${cheat_dist} saving ${saving}");
+                    cheats.insert((*pos, cheat), saving);
+                }
+            }
+        }
+    }
+
+    trace!("All Cheats:\n{cheats:?}");
+    debug!(
+        "Cheats, grouped by savings:\n{:?}",
+        cheats.values().filter(|v| **v >= 50).counts()
+    );
+
+    cheats.iter().filter(|(_, v)| **v >= 100).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn part_1_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Trace);
+        assert_eq!(part_1(EXAMPLE_1), 0);
+    }
+
+    #[test]
+    fn part_2_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Debug);
+        assert_eq!(part_2(EXAMPLE_1), 285);
+    }
+}
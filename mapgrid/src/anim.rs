@@ -0,0 +1,258 @@
+//! Recording a simulation one frame at a time, to watch or save afterwards.
+
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::{to_ppm, Cells, DenseGrid, Pixel, Renderer, Rgb};
+
+/// A frame rate that isn't a positive, finite number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BadFrameRate(pub f64);
+
+impl fmt::Display for BadFrameRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frames per second must be positive and finite, not {}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for BadFrameRate {}
+
+impl BadFrameRate {
+    /// `fps` itself, if it's a usable frame rate
+    pub fn check(fps: f64) -> Result<f64, BadFrameRate> {
+        if fps.is_finite() && fps > 0.0 {
+            Ok(fps)
+        } else {
+            Err(BadFrameRate(fps))
+        }
+    }
+}
+
+/// A single snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    text: String,
+    pixels: DenseGrid<Pixel>,
+}
+
+impl Frame {
+    /// As it would be printed
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn pixels(&self) -> &DenseGrid<Pixel> {
+        &self.pixels
+    }
+
+    fn lines(&self) -> usize {
+        self.text.lines().count()
+    }
+}
+
+/// A sequence of frames
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot a rendering, overlays and all
+    pub fn capture(&mut self, renderer: &Renderer) {
+        self.frames.push(Frame {
+            text: renderer.render(),
+            pixels: renderer.pixels(),
+        });
+    }
+
+    /// Snapshot a grid as `Renderer::new` would draw it
+    pub fn capture_grid<G, V>(&mut self, grid: &G)
+    where
+        G: Cells<V>,
+        V: ToString,
+    {
+        self.capture(&Renderer::new(grid));
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Each frame's output, moving the cursor back over the previous frame first
+    fn chunks(&self) -> impl Iterator<Item = String> + '_ {
+        let mut prev = 0;
+        self.frames.iter().map(move |f| {
+            let rewind = if prev > 0 {
+                format!("\x1b[{prev}A\x1b[J")
+            } else {
+                String::new()
+            };
+            prev = f.lines();
+            rewind + &f.text
+        })
+    }
+
+    /// Play back in place on a terminal at `fps` frames per second.
+    ///
+    /// Fails with `InvalidInput` unless `fps` is positive and finite.
+    pub fn replay<W: Write>(&self, out: &mut W, fps: f64) -> io::Result<()> {
+        let fps =
+            BadFrameRate::check(fps).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let delay = Duration::from_secs_f64(1.0 / fps);
+        for (i, chunk) in self.chunks().enumerate() {
+            if i > 0 {
+                thread::sleep(delay);
+            }
+            out.write_all(chunk.as_bytes())?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write every frame as `frame-00000.ppm` etc. into `dir`, creating it if need be
+    pub fn export_frames<F>(&self, dir: &Path, colour: F) -> io::Result<()>
+    where
+        F: Fn(&Pixel) -> Rgb,
+    {
+        fs::create_dir_all(dir)?;
+        for (i, f) in self.frames.iter().enumerate() {
+            let ppm = to_ppm(&f.pixels, |p| p.map_or([0, 0, 0], &colour));
+            fs::write(dir.join(format!("frame-{i:05}.ppm")), ppm)?;
+        }
+        Ok(())
+    }
+
+    /// An asciicast (v2) recording, for `asciinema play`
+    pub fn to_asciicast(&self, fps: f64) -> Result<String, BadFrameRate> {
+        let fps = BadFrameRate::check(fps)?;
+        let width = self
+            .frames
+            .iter()
+            .map(|f| f.pixels.width())
+            .max()
+            .unwrap_or(0);
+        let height = self.frames.iter().map(Frame::lines).max().unwrap_or(0);
+
+        let mut out = format!("{{\"version\": 2, \"width\": {width}, \"height\": {height}}}\n");
+        for (i, chunk) in self.chunks().enumerate() {
+            let time = i as f64 / fps;
+            let data = json_string(&chunk.replace('\n', "\r\n"));
+            out.push_str(&format!("[{time:.6}, \"o\", {data}]\n"));
+        }
+        Ok(out)
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Colour, Coord, Layer};
+    use std::collections::HashSet;
+
+    fn record() -> Recorder {
+        let walls: HashSet<Coord> = [[0, 0], [2, 0]].into();
+        let mut rec = Recorder::new();
+        for x in 0..3 {
+            rec.capture(
+                &Renderer::with_glyphs(&walls, |_| '#')
+                    .layer(Layer::new([[x, 1]]).glyph('@').colour(Colour::Red))
+                    .plain(),
+            );
+        }
+        rec
+    }
+
+    #[test]
+    fn frames() {
+        let rec = record();
+        assert_eq!(rec.len(), 3);
+        assert_eq!(rec.frames()[1].text(), "#.#\n.@.\n");
+        assert_eq!(
+            rec.frames()[1].pixels().get(&[1, 1]),
+            Some(&Pixel {
+                glyph: '@',
+                colour: Some(Colour::Red)
+            })
+        );
+
+        let mut out = vec![];
+        rec.replay(&mut out, 1000.0).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "#.#\n@..\n\x1b[2A\x1b[J#.#\n.@.\n\x1b[2A\x1b[J#.#\n..@\n"
+        );
+    }
+
+    #[test]
+    fn asciicast() {
+        let cast = record().to_asciicast(2.0).unwrap();
+        let lines: Vec<_> = cast.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], r#"{"version": 2, "width": 3, "height": 2}"#);
+        assert_eq!(lines[1], r##"[0.000000, "o", "#.#\r\n@..\r\n"]"##);
+        assert_eq!(
+            lines[2],
+            r##"[0.500000, "o", "\u001b[2A\u001b[J#.#\r\n.@.\r\n"]"##
+        );
+    }
+
+    #[test]
+    fn bad_frame_rates() {
+        let rec = record();
+        for fps in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let e = rec.replay(&mut vec![], fps).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+            assert!(rec.to_asciicast(fps).is_err());
+        }
+        assert_eq!(BadFrameRate::check(0.5), Ok(0.5));
+    }
+
+    #[test]
+    fn export() {
+        let dir = std::env::temp_dir().join(format!("mapgrid-anim-{}", std::process::id()));
+        record()
+            .export_frames(&dir, |p| p.colour.map_or([255; 3], Colour::rgb))
+            .unwrap();
+        let first = fs::read_to_string(dir.join("frame-00000.ppm")).unwrap();
+        assert!(first.starts_with("P3\n3 2\n255\n255 255 255 "));
+        assert!(dir.join("frame-00002.ppm").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
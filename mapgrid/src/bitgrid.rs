@@ -0,0 +1,308 @@
+use std::ops::{BitAnd, BitOr, RangeInclusive};
+
+use crate::{Cells, Coord, Grid};
+
+/// A fixed-size set of coordinates, one bit per cell.
+///
+/// Much cheaper than a `HashSet<Coord>` to fill, test and throw away,
+/// when you know the dimensions up front. Each row starts on a fresh word,
+/// so counting a row is quick.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    /// Coordinate of the top-left cell
+    origin: Coord,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    /// An empty grid with its top-left corner at [0, 0]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_origin([0, 0], width, height)
+    }
+
+    /// An empty grid with its top-left corner at `origin`
+    pub fn with_origin(origin: Coord, width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(64);
+        Self {
+            origin,
+            width,
+            height,
+            words_per_row,
+            bits: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    /// Is `coord` within the bounds of the grid? (The cell may still be clear.)
+    pub fn in_bounds(&self, coord: &Coord) -> bool {
+        self.index(coord).is_some()
+    }
+
+    /// (word, bit)
+    fn index(&self, coord: &Coord) -> Option<(usize, u32)> {
+        let x = coord[0].checked_sub(self.origin[0])?;
+        let y = coord[1].checked_sub(self.origin[1])?;
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            let (x, y) = (x as usize, y as usize);
+            Some((y * self.words_per_row + x / 64, (x % 64) as u32))
+        }
+    }
+
+    /// Out of bounds is never set
+    pub fn contains(&self, coord: &Coord) -> bool {
+        self.index(coord)
+            .is_some_and(|(w, b)| self.bits[w] & (1 << b) != 0)
+    }
+
+    /// Set a cell, returning whether it was clear before.
+    ///
+    /// Panics if `coord` is out of bounds.
+    pub fn insert(&mut self, coord: Coord) -> bool {
+        let (w, b) = self
+            .index(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside {:?}", self.bounds_inner()));
+        let was_clear = self.bits[w] & (1 << b) == 0;
+        self.bits[w] |= 1 << b;
+        was_clear
+    }
+
+    /// Clear a cell, returning whether it was set
+    pub fn remove(&mut self, coord: &Coord) -> bool {
+        match self.index(coord) {
+            Some((w, b)) => {
+                let was_set = self.bits[w] & (1 << b) != 0;
+                self.bits[w] &= !(1 << b);
+                was_set
+            }
+            None => false,
+        }
+    }
+
+    /// Clear every cell, keeping the dimensions
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Number of set cells
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|w| *w == 0)
+    }
+
+    /// Number of set cells in row `y`; 0 if it's out of bounds
+    pub fn row_count(&self, y: isize) -> usize {
+        let r = y - self.origin[1];
+        if r < 0 || r as usize >= self.height {
+            return 0;
+        }
+        let start = r as usize * self.words_per_row;
+        self.bits[start..start + self.words_per_row]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    /// Set cells, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = Coord> + '_ {
+        let [ox, oy] = self.origin;
+        self.bits.iter().enumerate().flat_map(move |(i, &word)| {
+            let y = oy + (i / self.words_per_row) as isize;
+            let x0 = ox + (i % self.words_per_row * 64) as isize;
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let b = word.trailing_zeros();
+                word &= word - 1;
+                Some([x0 + b as isize, y])
+            })
+        })
+    }
+
+    /// Set every cell that's set in `other`.
+    ///
+    /// Panics unless both grids have the same origin and dimensions.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.assert_same_shape(other);
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a |= b;
+        }
+    }
+
+    /// Clear every cell that isn't set in `other`.
+    ///
+    /// Panics unless both grids have the same origin and dimensions.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.assert_same_shape(other);
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a &= b;
+        }
+    }
+
+    fn assert_same_shape(&self, other: &BitGrid) {
+        assert!(
+            self.origin == other.origin && self.width == other.width && self.height == other.height,
+            "BitGrids differ in shape: {:?} vs {:?}",
+            self.bounds_inner(),
+            other.bounds_inner()
+        );
+    }
+
+    fn bounds_inner(&self) -> [RangeInclusive<isize>; 2] {
+        let [ox, oy] = self.origin;
+        [
+            ox..=ox + self.width as isize - 1,
+            oy..=oy + self.height as isize - 1,
+        ]
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.union_with(rhs);
+        out
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out.intersect_with(rhs);
+        out
+    }
+}
+
+impl<V> Grid<V> for BitGrid {
+    /// Width is that of the longest line. Note: the value of V is disregarded
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> BitGrid {
+        let width = input.lines().map(|s| s.chars().count()).max().unwrap_or(0);
+        let height = input.lines().count();
+        let mut out = BitGrid::new(width, height);
+
+        for (row, s) in input.lines().enumerate() {
+            for (col, cha) in s.chars().enumerate() {
+                if f(cha).is_some() {
+                    out.insert([col as isize, row as isize]);
+                }
+            }
+        }
+        out
+    }
+
+    /// Constant time; includes clear cells at the edges
+    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
+        self.bounds_inner()
+    }
+
+    fn visualise(&self) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        let [xs, ys] = self.bounds_inner();
+
+        for y in ys {
+            for x in xs.clone() {
+                out.push(if self.contains(&[x, y]) { '#' } else { '.' });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Cells<()> for BitGrid {
+    fn cell(&self, coord: &Coord) -> Option<&()> {
+        self.contains(coord).then_some(&())
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a ())>
+    where
+        (): 'a,
+    {
+        self.iter().map(|k| (k, &()))
+    }
+}
+
+impl Extend<Coord> for BitGrid {
+    /// Panics if any `coord` is out of bounds
+    fn extend<I: IntoIterator<Item = Coord>>(&mut self, iter: I) {
+        for k in iter {
+            self.insert(k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const SHAPE: &str = r"#...#
+.#.#.
+..#..";
+
+    #[test]
+    fn bits() {
+        let mut g = <BitGrid as Grid>::from_str_with(SHAPE, |c| (c == '#').then_some(c));
+        assert_eq!(g.len(), 5);
+        assert_eq!(<BitGrid as Grid>::bounds(&g), [0..=4, 0..=2]);
+        assert_eq!(<BitGrid as Grid>::visualise(&g), SHAPE.to_string() + "\n");
+        assert_eq!(g.row_count(1), 2);
+        assert_eq!(g.row_count(9), 0);
+
+        assert!(g.contains(&[2, 2]));
+        assert!(!g.contains(&[-1, 0]));
+        assert!(!g.insert([2, 2]));
+        assert!(g.remove(&[2, 2]));
+        assert!(!g.remove(&[2, 2]));
+
+        let set: HashSet<Coord> = g.iter().collect();
+        assert_eq!(set, [[0, 0], [4, 0], [1, 1], [3, 1]].into());
+        assert_eq!(g.iter().next(), Some([0, 0]));
+
+        g.clear();
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn wide_rows() {
+        let mut a = BitGrid::with_origin([-10, 5], 130, 2);
+        a.extend([[-10, 5], [63, 5], [119, 6]]);
+        let mut b = BitGrid::with_origin([-10, 5], 130, 2);
+        b.extend([[63, 5], [54, 6]]);
+
+        assert_eq!(a.row_count(5), 2);
+        assert_eq!((&a | &b).len(), 4);
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [[63, 5]]);
+        assert_eq!(a.iter().collect::<Vec<_>>(), [[-10, 5], [63, 5], [119, 6]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        BitGrid::new(3, 3).insert([3, 0]);
+    }
+}
@@ -0,0 +1,174 @@
+use std::ops::{Deref, DerefMut, RangeInclusive};
+
+use crate::{Cells, Coord, FromCells, Grid};
+
+/// A sparse grid that remembers the extent it was parsed with.
+///
+/// A plain map or set only knows about the cells present in it, so it loses
+/// empty rows and columns at the edges and has to scan every key to find its bounds.
+/// This keeps the declared dimensions alongside, and derefs to the inner grid.
+///
+/// The bounds don't grow if cells are added outside them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bounded<G> {
+    grid: G,
+    bounds: [RangeInclusive<isize>; 2],
+}
+
+impl<G> Bounded<G> {
+    /// `width` by `height`, with its top-left corner at [0, 0]
+    pub fn new(grid: G, width: usize, height: usize) -> Self {
+        Self::with_bounds(grid, [0..=width as isize - 1, 0..=height as isize - 1])
+    }
+
+    pub fn with_bounds(grid: G, bounds: [RangeInclusive<isize>; 2]) -> Self {
+        Self { grid, bounds }
+    }
+
+    /// Constant time; includes empty cells at the edges
+    pub fn bounds(&self) -> [RangeInclusive<isize>; 2] {
+        self.bounds.clone()
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds[0].clone().count()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds[1].clone().count()
+    }
+
+    pub fn in_bounds(&self, coord: &Coord) -> bool {
+        self.bounds[0].contains(&coord[0]) && self.bounds[1].contains(&coord[1])
+    }
+
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+}
+
+impl<G> Deref for Bounded<G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.grid
+    }
+}
+
+impl<G> DerefMut for Bounded<G> {
+    fn deref_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+}
+
+impl<G, V> Grid<V> for Bounded<G>
+where
+    G: Grid<V> + Cells<V>,
+{
+    /// Width is that of the longest line, even where it's empty space
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> Self {
+        let width = input.lines().map(|s| s.chars().count()).max().unwrap_or(0);
+        let height = input.lines().count();
+        Self::new(G::from_str_with(input, f), width, height)
+    }
+
+    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
+        Bounded::bounds(self)
+    }
+
+    /// As the inner grid would draw it, padded out to the full extent
+    fn visualise(&self) -> String {
+        let [xs, ys] = self.bounds();
+        let mut canvas = vec![vec!['.'; self.width()]; self.height()];
+
+        if self.grid.cells().next().is_some() {
+            let [inner_xs, inner_ys] = self.grid.bounds();
+            for (y, line) in inner_ys.zip(self.grid.visualise().lines()) {
+                for (x, cha) in inner_xs.clone().zip(line.chars()) {
+                    if self.in_bounds(&[x, y]) {
+                        canvas[(y - ys.start()) as usize][(x - xs.start()) as usize] = cha;
+                    }
+                }
+            }
+        }
+
+        let mut out = String::with_capacity((self.width() + 1) * self.height());
+        for row in canvas {
+            out.extend(row);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<G, V> Cells<V> for Bounded<G>
+where
+    G: Cells<V>,
+{
+    fn cell(&self, coord: &Coord) -> Option<&V> {
+        self.grid.cell(coord)
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a V)>
+    where
+        V: 'a,
+    {
+        self.grid.cells()
+    }
+}
+
+/// Keeps `bounds` as the declared extent
+impl<G, V> FromCells<V> for Bounded<G>
+where
+    G: FromCells<V>,
+{
+    fn from_cells<I>(bounds: [RangeInclusive<isize>; 2], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord, V)>,
+    {
+        Self::with_bounds(G::from_cells(bounds.clone(), cells), bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TryGrid;
+    use std::collections::{HashMap, HashSet};
+
+    const ANTENNAE: &str = r"......
+..a...
+.a....
+......
+";
+
+    #[test]
+    fn keeps_extent() {
+        let grid: Bounded<HashMap<Coord, char>> =
+            Grid::from_str_with(ANTENNAE, |c| (c != '.').then_some(c));
+        assert_eq!(grid.bounds(), [0..=5, 0..=3]);
+        assert_eq!((grid.width(), grid.height()), (6, 4));
+        assert!(grid.in_bounds(&[5, 3]));
+        assert!(!grid.in_bounds(&[6, 0]));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.visualise(), ANTENNAE);
+
+        let inner = grid.into_inner();
+        assert_eq!(<HashMap<_, _> as Grid>::bounds(&inner), [1..=2, 1..=2]);
+    }
+
+    #[test]
+    fn sets_and_parsing() {
+        let mut walls: Bounded<HashSet<Coord>> =
+            TryGrid::try_from_str_with("...\n.#.", |c| Ok::<_, ()>((c == '#').then_some(())))
+                .unwrap();
+        assert_eq!(walls.bounds(), [0..=2, 0..=1]);
+
+        walls.insert([7, 7]);
+        assert_eq!(walls.bounds(), [0..=2, 0..=1]);
+        assert_eq!(<Bounded<_> as Grid<()>>::visualise(&walls), "...\n.#.\n");
+
+        let empty = Bounded::new(HashSet::<Coord>::new(), 2, 1);
+        assert_eq!(<Bounded<_> as Grid<()>>::visualise(&empty), "..\n");
+    }
+}
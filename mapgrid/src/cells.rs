@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::{add, neighbour_offsets, orthogonal_offsets, Coord, Dir4, Dir8};
+
+/// Random access to the cells of a grid, in 2 dimensions unless `N` says otherwise.
+///
+/// Sets have no values, so they are `Cells<()>`.
+pub trait Cells<V, const N: usize = 2> {
+    /// The value at `coord`, if that cell is present
+    fn cell(&self, coord: &Coord<N>) -> Option<&V>;
+
+    /// Every present cell (in no particular order)
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        V: 'a;
+
+    /// Present cells at each of `coord + offset`
+    fn neighbours_with<'a, I>(
+        &'a self,
+        coord: Coord<N>,
+        offsets: I,
+    ) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        I: IntoIterator<Item = Coord<N>>,
+        V: 'a,
+    {
+        offsets.into_iter().filter_map(move |d| {
+            let next = add(coord, d);
+            self.cell(&next).map(|v| (next, v))
+        })
+    }
+
+    /// Present cells among the `2N` one step along a single axis from `coord`
+    fn neighbours_orthogonal<'a>(
+        &'a self,
+        coord: Coord<N>,
+    ) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        V: 'a,
+    {
+        self.neighbours_with(coord, orthogonal_offsets())
+    }
+
+    /// Present cells among the `3^N - 1` surrounding `coord`
+    fn neighbours_all<'a>(&'a self, coord: Coord<N>) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        V: 'a,
+    {
+        self.neighbours_with(coord, neighbour_offsets())
+    }
+
+    /// Present cells orthogonally adjacent to `coord`, clockwise from north
+    fn neighbours4<'a>(&'a self, coord: Coord) -> impl Iterator<Item = (Coord, &'a V)>
+    where
+        V: 'a,
+        Self: Cells<V>,
+    {
+        Cells::<V>::neighbours_with(self, coord, Dir4::ALL.map(Dir4::to_coord))
+    }
+
+    /// Present cells orthogonally or diagonally adjacent to `coord`, clockwise from north
+    fn neighbours8<'a>(&'a self, coord: Coord) -> impl Iterator<Item = (Coord, &'a V)>
+    where
+        V: 'a,
+        Self: Cells<V>,
+    {
+        Cells::<V>::neighbours_with(self, coord, Dir8::ALL.map(Dir8::to_coord))
+    }
+
+    /// The 2D slice where the axes after x and y are `rest`
+    fn layer(&self, rest: &[isize]) -> HashMap<Coord, V>
+    where
+        V: Clone,
+    {
+        assert_eq!(
+            rest.len() + 2,
+            N,
+            "layer needs a position on each extra axis"
+        );
+        self.cells()
+            .filter(|(k, _)| k[2..] == *rest)
+            .map(|(k, v)| ([k[0], k[1]], v.clone()))
+            .collect()
+    }
+}
+
+impl<const N: usize, V> Cells<V, N> for HashMap<Coord<N>, V> {
+    fn cell(&self, coord: &Coord<N>) -> Option<&V> {
+        self.get(coord)
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(k, v)| (*k, v))
+    }
+}
+
+impl<const N: usize, V> Cells<V, N> for BTreeMap<Coord<N>, V> {
+    fn cell(&self, coord: &Coord<N>) -> Option<&V> {
+        self.get(coord)
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord<N>, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(k, v)| (*k, v))
+    }
+}
+
+impl<const N: usize> Cells<(), N> for HashSet<Coord<N>> {
+    fn cell(&self, coord: &Coord<N>) -> Option<&()> {
+        self.contains(coord).then_some(&())
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord<N>, &'a ())>
+    where
+        (): 'a,
+    {
+        self.iter().map(|k| (*k, &()))
+    }
+}
+
+impl<const N: usize> Cells<(), N> for BTreeSet<Coord<N>> {
+    fn cell(&self, coord: &Coord<N>) -> Option<&()> {
+        self.contains(coord).then_some(&())
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord<N>, &'a ())>
+    where
+        (): 'a,
+    {
+        self.iter().map(|k| (*k, &()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DenseGrid, Grid};
+
+    const DIGITS: &str = r"123
+4.6
+789";
+
+    #[test]
+    fn neighbours() {
+        let hm = <HashMap<Coord, char> as Grid>::from_str_with(DIGITS, |c| {
+            c.is_ascii_digit().then_some(c)
+        });
+        let dense = DenseGrid::from_str_with(DIGITS, |c| c.is_ascii_digit().then_some(c));
+
+        let n4: Vec<char> = hm.neighbours4([1, 1]).map(|(_, v)| *v).collect();
+        assert_eq!(n4, ['2', '6', '8', '4']);
+
+        let n8: Vec<(Coord, char)> = dense.neighbours8([1, 1]).map(|(k, v)| (k, *v)).collect();
+        assert_eq!(n8.len(), 8);
+        assert_eq!(n8[1], ([2, 0], '3'));
+
+        // corners only have in-bounds neighbours, and the empty middle is skipped
+        let corner: Vec<Coord> = dense.neighbours8([0, 0]).map(|(k, _)| k).collect();
+        assert_eq!(corner, [[1, 0], [0, 1]]);
+
+        let knight: Vec<char> = hm
+            .neighbours_with([0, 0], [[1, 2], [2, 1], [-1, 2]])
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(knight, ['8', '6']);
+    }
+
+    #[test]
+    fn sets() {
+        let hs: HashSet<Coord> = [[0, 0], [0, 1], [5, 5]].into_iter().collect();
+        assert_eq!(hs.neighbours4([0, 0]).count(), 1);
+        assert_eq!(hs.cells().count(), 3);
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use crate::{Cells, Coord, Grid};
+
+/// A contiguous, row-major grid with known dimensions.
+///
+/// Cells may still be empty, so that parsing with `from_str_with`
+/// means the same thing here as it does for the sparse impls.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DenseGrid<V> {
+    /// Coordinate of the top-left cell
+    origin: Coord,
+    width: usize,
+    height: usize,
+    cells: Vec<Option<V>>,
+}
+
+impl<V> DenseGrid<V> {
+    /// An empty grid with its top-left corner at [0, 0]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_origin([0, 0], width, height)
+    }
+
+    /// An empty grid with its top-left corner at `origin`
+    pub fn with_origin(origin: Coord, width: usize, height: usize) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        cells.resize_with(width * height, || None);
+        Self {
+            origin,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        Self {
+            origin: [0, 0],
+            width,
+            height,
+            cells: vec![Some(value); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    /// Is `coord` within the bounds of the grid? (The cell may still be empty.)
+    pub fn in_bounds(&self, coord: &Coord) -> bool {
+        self.index(coord).is_some()
+    }
+
+    fn index(&self, coord: &Coord) -> Option<usize> {
+        let x = coord[0].checked_sub(self.origin[0])?;
+        let y = coord[1].checked_sub(self.origin[1])?;
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(y as usize * self.width + x as usize)
+        }
+    }
+
+    fn coord(&self, index: usize) -> Coord {
+        [
+            self.origin[0] + (index % self.width) as isize,
+            self.origin[1] + (index / self.width) as isize,
+        ]
+    }
+
+    pub fn get(&self, coord: &Coord) -> Option<&V> {
+        self.index(coord).and_then(|i| self.cells[i].as_ref())
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord) -> Option<&mut V> {
+        self.index(coord).and_then(|i| self.cells[i].as_mut())
+    }
+
+    pub fn contains_key(&self, coord: &Coord) -> bool {
+        self.get(coord).is_some()
+    }
+
+    /// Set a cell, returning its previous value.
+    ///
+    /// Panics if `coord` is out of bounds.
+    pub fn insert(&mut self, coord: Coord, value: V) -> Option<V> {
+        let i = self
+            .index(&coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside {:?}", self.bounds_inner()));
+        self.cells[i].replace(value)
+    }
+
+    /// Empty a cell, returning its previous value
+    pub fn remove(&mut self, coord: &Coord) -> Option<V> {
+        self.index(coord).and_then(|i| self.cells[i].take())
+    }
+
+    /// Number of non-empty cells
+    pub fn len(&self) -> usize {
+        self.cells.iter().filter(|c| c.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|c| c.is_none())
+    }
+
+    /// Non-empty cells, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &V)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (self.coord(i), v)))
+    }
+
+    /// Non-empty cells, in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut V)> {
+        let [ox, oy] = self.origin;
+        let width = self.width;
+        self.cells.iter_mut().enumerate().filter_map(move |(i, v)| {
+            v.as_mut()
+                .map(|v| ([ox + (i % width) as isize, oy + (i / width) as isize], v))
+        })
+    }
+
+    /// One row, including empty cells; `None` if `y` is out of bounds
+    pub fn row(&self, y: isize) -> Option<&[Option<V>]> {
+        let r = y.checked_sub(self.origin[1])?;
+        if r < 0 || r as usize >= self.height {
+            return None;
+        }
+        let start = r as usize * self.width;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    fn bounds_inner(&self) -> [RangeInclusive<isize>; 2] {
+        let [ox, oy] = self.origin;
+        [
+            ox..=ox + self.width as isize - 1,
+            oy..=oy + self.height as isize - 1,
+        ]
+    }
+}
+
+impl<V> Grid<V> for DenseGrid<V>
+where
+    V: ToString,
+{
+    /// Width is that of the longest line; shorter lines are padded with empty cells
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> DenseGrid<V> {
+        let width = input.lines().map(|s| s.chars().count()).max().unwrap_or(0);
+        let height = input.lines().count();
+        let mut out = DenseGrid::new(width, height);
+
+        for (row, s) in input.lines().enumerate() {
+            for (col, cha) in s.chars().enumerate() {
+                out.cells[row * width + col] = f(cha);
+            }
+        }
+        out
+    }
+
+    /// Constant time; includes empty cells at the edges
+    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
+        self.bounds_inner()
+    }
+
+    /// Visualise the grid. Note that only the first character is used.
+    fn visualise(&self) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.cells.chunks(self.width.max(1)).take(self.height) {
+            for v in row {
+                match v {
+                    Some(v) => out.push(v.to_string().chars().next().unwrap_or('#')),
+                    None => out.push('.'),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<V> Cells<V> for DenseGrid<V> {
+    fn cell(&self, coord: &Coord) -> Option<&V> {
+        self.get(coord)
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<V> FromIterator<(Coord, V)> for DenseGrid<V> {
+    /// The grid is sized to fit exactly the cells given
+    fn from_iter<I: IntoIterator<Item = (Coord, V)>>(iter: I) -> Self {
+        let items: Vec<(Coord, V)> = iter.into_iter().collect();
+
+        if items.is_empty() {
+            return DenseGrid::new(0, 0);
+        }
+
+        let xmin = items.iter().map(|(k, _)| k[0]).min().unwrap_or(0);
+        let xmax = items.iter().map(|(k, _)| k[0]).max().unwrap_or(0);
+        let ymin = items.iter().map(|(k, _)| k[1]).min().unwrap_or(0);
+        let ymax = items.iter().map(|(k, _)| k[1]).max().unwrap_or(0);
+
+        let mut out = DenseGrid::with_origin(
+            [xmin, ymin],
+            (xmax - xmin + 1) as usize,
+            (ymax - ymin + 1) as usize,
+        );
+        for (k, v) in items {
+            out.insert(k, v);
+        }
+        out
+    }
+}
+
+impl FromIterator<Coord> for DenseGrid<()> {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        iter.into_iter().map(|k| (k, ())).collect()
+    }
+}
+
+impl<V> IntoIterator for DenseGrid<V> {
+    type Item = (Coord, V);
+    type IntoIter = std::vec::IntoIter<(Coord, V)>;
+
+    /// Non-empty cells, in row-major order
+    fn into_iter(self) -> Self::IntoIter {
+        let [ox, oy] = self.origin;
+        let width = self.width;
+        self.cells
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| {
+                v.map(|v| ([ox + (i % width) as isize, oy + (i / width) as isize], v))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<V> From<HashMap<Coord, V>> for DenseGrid<V> {
+    fn from(value: HashMap<Coord, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V> From<BTreeMap<Coord, V>> for DenseGrid<V> {
+    fn from(value: BTreeMap<Coord, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<HashSet<Coord>> for DenseGrid<()> {
+    fn from(value: HashSet<Coord>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<BTreeSet<Coord>> for DenseGrid<()> {
+    fn from(value: BTreeSet<Coord>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V> From<DenseGrid<V>> for HashMap<Coord, V> {
+    fn from(value: DenseGrid<V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V> From<DenseGrid<V>> for BTreeMap<Coord, V> {
+    fn from(value: DenseGrid<V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+/// Note: only the occupied positions are kept
+impl<V> From<DenseGrid<V>> for HashSet<Coord> {
+    fn from(value: DenseGrid<V>) -> Self {
+        value.into_iter().map(|(k, _)| k).collect()
+    }
+}
+
+/// Note: only the occupied positions are kept
+impl<V> From<DenseGrid<V>> for BTreeSet<Coord> {
+    fn from(value: DenseGrid<V>) -> Self {
+        value.into_iter().map(|(k, _)| k).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAGGED: &str = r"#..#
+.##
+#";
+
+    #[test]
+    fn ragged_rows() {
+        let g = DenseGrid::from_str_with(RAGGED, |c| if c == '#' { Some(c) } else { None });
+
+        assert_eq!(g.width(), 4);
+        assert_eq!(g.height(), 3);
+        assert_eq!(g.bounds(), [0..=3, 0..=2]);
+        assert_eq!(g.len(), 5);
+        assert_eq!(g.get(&[3, 0]), Some(&'#'));
+        assert_eq!(g.get(&[3, 1]), None);
+        assert_eq!(g.get(&[-1, 0]), None);
+        assert_eq!(g.visualise(), "#..#\n.##.\n#...\n");
+    }
+
+    #[test]
+    fn get_mut_and_insert() {
+        let mut g = DenseGrid::filled(3, 2, 0_usize);
+        *g.get_mut(&[2, 1]).unwrap() += 5;
+        assert_eq!(g.insert([0, 0], 7), Some(0));
+        assert_eq!(g.remove(&[1, 0]), Some(0));
+        assert_eq!(g.visualise(), "7.0\n005\n");
+    }
+
+    #[test]
+    fn sparse_round_trip() {
+        let hm: HashMap<Coord, char> = [([-2, 5], 'a'), ([1, 7], 'b')].into_iter().collect();
+        let dense = DenseGrid::from(hm.clone());
+
+        assert_eq!(dense.origin(), [-2, 5]);
+        assert_eq!(dense.bounds(), hm.bounds());
+        assert_eq!(dense.visualise(), hm.visualise());
+        assert_eq!(HashMap::from(dense), hm);
+
+        let hs: HashSet<Coord> = hm.keys().cloned().collect();
+        let dense = DenseGrid::from(hs.clone());
+        assert_eq!(HashSet::from(dense), hs);
+    }
+}
@@ -0,0 +1,243 @@
+//! Comparing two grids cell by cell, mostly for tests that fail.
+
+use std::collections::HashMap;
+
+use crate::{Cells, Colour, Coord, Layer, Renderer};
+
+/// How one cell differs between the left and right grids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellDiff<'a, V> {
+    /// Only in the right grid
+    Added(&'a V),
+    /// Only in the left grid
+    Removed(&'a V),
+    /// In both, but not equal: (left, right)
+    Changed(&'a V, &'a V),
+}
+
+impl<V> CellDiff<'_, V> {
+    /// `+`, `-` or `~`
+    pub fn marker(&self) -> char {
+        match self {
+            CellDiff::Added(_) => '+',
+            CellDiff::Removed(_) => '-',
+            CellDiff::Changed(..) => '~',
+        }
+    }
+}
+
+/// Every cell that differs between two grids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridDiff<'a, V> {
+    left: HashMap<Coord, &'a V>,
+    right: HashMap<Coord, &'a V>,
+    /// In reading order
+    changes: Vec<(Coord, CellDiff<'a, V>)>,
+}
+
+impl<'a, V> GridDiff<'a, V>
+where
+    V: PartialEq,
+{
+    pub fn new<L, R>(left: &'a L, right: &'a R) -> Self
+    where
+        L: Cells<V>,
+        R: Cells<V>,
+    {
+        let left: HashMap<Coord, &V> = left.cells().collect();
+        let right: HashMap<Coord, &V> = right.cells().collect();
+
+        let mut changes: Vec<(Coord, CellDiff<V>)> = left
+            .iter()
+            .filter_map(|(k, l)| match right.get(k) {
+                None => Some((*k, CellDiff::Removed(*l))),
+                Some(r) if l != r => Some((*k, CellDiff::Changed(*l, *r))),
+                Some(_) => None,
+            })
+            .chain(
+                right
+                    .iter()
+                    .filter(|(k, _)| !left.contains_key(*k))
+                    .map(|(k, r)| (*k, CellDiff::Added(*r))),
+            )
+            .collect();
+        changes.sort_by_key(|([x, y], _)| (*y, *x));
+
+        Self {
+            left,
+            right,
+            changes,
+        }
+    }
+}
+
+impl<'a, V> GridDiff<'a, V> {
+    pub fn changes(&self) -> &[(Coord, CellDiff<'a, V>)] {
+        &self.changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Are the grids the same?
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The smallest area covering both grids
+    fn area(&self) -> [std::ops::RangeInclusive<isize>; 2] {
+        let coords = || self.left.keys().chain(self.right.keys());
+        let range = |i: usize| {
+            let min = coords().map(|c| c[i]).min().unwrap_or(0);
+            let max = coords().map(|c| c[i]).max().unwrap_or(0);
+            min..=max
+        };
+        [range(0), range(1)]
+    }
+
+    /// The left grid, the right grid, and a map of where they differ, next to each other.
+    ///
+    /// Plain text, so it survives being written to a log.
+    pub fn side_by_side<F: Fn(&V) -> char>(&self, glyph: F) -> String {
+        let [xs, ys] = self.area();
+        let draw = |cells: &HashMap<Coord, &V>| {
+            Renderer::with_glyphs(cells, |v| glyph(v))
+                .area(xs.clone(), ys.clone())
+                .plain()
+                .render()
+        };
+        let markers: HashMap<Coord, char> =
+            self.changes.iter().map(|(k, d)| (*k, d.marker())).collect();
+        let panels = [
+            draw(&self.left),
+            draw(&self.right),
+            Renderer::new(&markers)
+                .area(xs.clone(), ys.clone())
+                .plain()
+                .render(),
+        ];
+
+        let width = xs.count().max(5);
+        let mut out = format!("{:width$} | {:width$} | diff\n", "left", "right");
+        let [l, r, d] = panels.each_ref().map(|p| p.lines());
+        for ((l, r), d) in l.zip(r).zip(d) {
+            out.push_str(&format!("{l:width$} | {r:width$} | {d}\n"));
+        }
+        out
+    }
+
+    /// The right grid, with added cells in green, changed cells in yellow,
+    /// and removed cells drawn from the left grid in red
+    pub fn overlay<F: Fn(&V) -> char>(&self, glyph: F) -> Renderer {
+        let [xs, ys] = self.area();
+        let layer = |colour, pick: &dyn Fn(&CellDiff<'a, V>) -> Option<&'a V>| {
+            Layer::glyphs(
+                self.changes
+                    .iter()
+                    .filter_map(|(k, d)| pick(d).map(|v| (*k, glyph(v)))),
+            )
+            .colour(colour)
+        };
+        Renderer::with_glyphs(&self.right, |v| glyph(v))
+            .area(xs, ys)
+            .layer(layer(Colour::Green, &|d| match d {
+                CellDiff::Added(r) => Some(*r),
+                _ => None,
+            }))
+            .layer(layer(Colour::Yellow, &|d| match d {
+                CellDiff::Changed(_, r) => Some(*r),
+                _ => None,
+            }))
+            .layer(layer(Colour::Red, &|d| match d {
+                CellDiff::Removed(l) => Some(*l),
+                _ => None,
+            }))
+    }
+}
+
+/// Like `assert_eq!` for grids, but on failure prints the grids side by side
+/// along with where they differ.
+///
+/// Values are drawn with the first character of their `to_string()`, or pass a glyph function:
+/// `assert_grid_eq!(walls, expected, |_| '#')`.
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_grid_eq!($left, $right, |v| v
+            .to_string()
+            .chars()
+            .next()
+            .unwrap_or('#'))
+    };
+    ($left:expr, $right:expr, $glyph:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = $crate::GridDiff::new(left, right);
+                if !diff.is_empty() {
+                    panic!(
+                        "grids differ in {} cells:\n{}",
+                        diff.len(),
+                        diff.side_by_side($glyph)
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DenseGrid, Grid};
+    use std::collections::HashSet;
+
+    fn parse(s: &str) -> HashMap<Coord, char> {
+        HashMap::from_str_with(s, |c| (c != '.').then_some(c))
+    }
+
+    #[test]
+    fn differences() {
+        let left = parse("#.O\n.@.");
+        let right = parse("#..\n.@O\nO..");
+        let diff = GridDiff::new(&left, &right);
+
+        assert_eq!(
+            diff.changes(),
+            [
+                ([2, 0], CellDiff::Removed(&'O')),
+                ([2, 1], CellDiff::Added(&'O')),
+                ([0, 2], CellDiff::Added(&'O')),
+            ]
+        );
+        assert_eq!(
+            diff.side_by_side(|c| *c),
+            "left  | right | diff\n\
+             #.O   | #..   | ..-\n\
+             .@.   | .@O   | ..+\n\
+             ...   | O..   | +..\n"
+        );
+        assert_eq!(diff.overlay(|c| *c).plain().render(), "#.O\n.@O\nO..\n");
+
+        let changed = parse("#.O\n.#.");
+        assert_eq!(
+            GridDiff::new(&left, &changed).changes(),
+            [([1, 1], CellDiff::Changed(&'@', &'#'))]
+        );
+    }
+
+    #[test]
+    fn macro_passes() {
+        let dense = DenseGrid::from(parse("#.O\n.@."));
+        assert_grid_eq!(parse("#.O\n.@."), dense);
+
+        let walls: HashSet<Coord> = [[0, 0], [1, 1]].into();
+        assert_grid_eq!(walls, walls.clone(), |_| '#');
+    }
+
+    #[test]
+    #[should_panic(expected = "grids differ in 1 cells")]
+    fn macro_fails() {
+        assert_grid_eq!(parse("#."), parse("##"));
+    }
+}
@@ -0,0 +1,303 @@
+use std::{fmt, str::FromStr};
+
+use crate::Coord;
+
+/// The four cardinal directions.
+///
+/// These use screen coordinates, as `from_str_with` does:
+/// +X is east (right) and +Y is **south** (down).
+/// So `North.to_coord()` is `[0, -1]`, and turning right from north faces east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// The four cardinal and four intercardinal directions, in screen coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// A character (or string) that isn't a recognised direction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(pub String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+impl Dir4 {
+    /// Clockwise from north
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Rotate 90° anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Rotate 90° clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Unit step in this direction
+    pub fn to_coord(self) -> Coord {
+        match self {
+            Dir4::North => [0, -1],
+            Dir4::East => [1, 0],
+            Dir4::South => [0, 1],
+            Dir4::West => [-1, 0],
+        }
+    }
+
+    /// Inverse of `to_coord`
+    pub fn from_coord(c: Coord) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.to_coord() == c)
+    }
+
+    /// One of `^>v<`
+    pub fn to_char(self) -> char {
+        match self {
+            Dir4::North => '^',
+            Dir4::East => '>',
+            Dir4::South => 'v',
+            Dir4::West => '<',
+        }
+    }
+}
+
+impl Dir8 {
+    /// Clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Rotate 45° anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Rotate 45° clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Unit step in this direction (diagonals move on both axes)
+    pub fn to_coord(self) -> Coord {
+        match self {
+            Dir8::North => [0, -1],
+            Dir8::NorthEast => [1, -1],
+            Dir8::East => [1, 0],
+            Dir8::SouthEast => [1, 1],
+            Dir8::South => [0, 1],
+            Dir8::SouthWest => [-1, 1],
+            Dir8::West => [-1, 0],
+            Dir8::NorthWest => [-1, -1],
+        }
+    }
+
+    /// Inverse of `to_coord`
+    pub fn from_coord(c: Coord) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.to_coord() == c)
+    }
+
+    /// `None` for the diagonals
+    pub fn to_dir4(self) -> Option<Dir4> {
+        match self {
+            Dir8::North => Some(Dir4::North),
+            Dir8::East => Some(Dir4::East),
+            Dir8::South => Some(Dir4::South),
+            Dir8::West => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.to_dir4().is_none()
+    }
+
+    /// An arrow: `↑↗→↘↓↙←↖`
+    pub fn to_char(self) -> char {
+        match self {
+            Dir8::North => '↑',
+            Dir8::NorthEast => '↗',
+            Dir8::East => '→',
+            Dir8::SouthEast => '↘',
+            Dir8::South => '↓',
+            Dir8::SouthWest => '↙',
+            Dir8::West => '←',
+            Dir8::NorthWest => '↖',
+        }
+    }
+}
+
+impl From<Dir4> for Coord {
+    fn from(value: Dir4) -> Self {
+        value.to_coord()
+    }
+}
+
+impl From<Dir8> for Coord {
+    fn from(value: Dir8) -> Self {
+        value.to_coord()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(value: Dir4) -> Self {
+        match value {
+            Dir4::North => Dir8::North,
+            Dir4::East => Dir8::East,
+            Dir4::South => Dir8::South,
+            Dir4::West => Dir8::West,
+        }
+    }
+}
+
+/// Accepts `^>v<`, arrows, `NESW` and `URDL`
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | '↑' | 'N' | 'U' => Ok(Dir4::North),
+            '>' | '→' | 'E' | 'R' => Ok(Dir4::East),
+            'v' | '↓' | 'S' | 'D' => Ok(Dir4::South),
+            '<' | '←' | 'W' | 'L' => Ok(Dir4::West),
+            e => Err(ParseDirError(e.to_string())),
+        }
+    }
+}
+
+/// As for `Dir4`, plus the diagonal arrows `↗↘↙↖`
+impl TryFrom<char> for Dir8 {
+    type Error = ParseDirError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '↗' => Ok(Dir8::NorthEast),
+            '↘' => Ok(Dir8::SouthEast),
+            '↙' => Ok(Dir8::SouthWest),
+            '↖' => Ok(Dir8::NorthWest),
+            e => Dir4::try_from(e).map(Dir8::from),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+/// Also accepts two-letter compass points like `NE`
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NorthEast),
+            "SE" => Ok(Dir8::SouthEast),
+            "SW" => Ok(Dir8::SouthWest),
+            "NW" => Ok(Dir8::NorthWest),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Dir8::try_from(c),
+                    _ => Err(ParseDirError(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::North.turn_right(), Dir4::East);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir4::East.reverse(), Dir4::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthWest.turn_right(), Dir8::North);
+        assert_eq!(Dir8::SouthEast.reverse(), Dir8::NorthWest);
+
+        for d in Dir4::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(Dir4::from_coord(d.to_coord()), Some(d));
+        }
+        for d in Dir8::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            if let Some(d4) = d.to_dir4() {
+                assert_eq!(d.turn_left().turn_left(), Dir8::from(d4.turn_left()));
+            }
+            assert_eq!(Dir8::from_coord(d.to_coord()), Some(d));
+        }
+    }
+
+    #[test]
+    fn screen_coordinates() {
+        // +Y is south, so a right turn from north heads towards +X
+        assert_eq!(Dir4::North.to_coord(), [0, -1]);
+        assert_eq!(Dir4::North.turn_right().to_coord(), [1, 0]);
+        assert_eq!(Dir8::SouthWest.to_coord(), [-1, 1]);
+    }
+
+    #[test]
+    fn parsing() {
+        for (s, d) in [
+            ('^', Dir4::North),
+            ('S', Dir4::South),
+            ('L', Dir4::West),
+            ('→', Dir4::East),
+        ] {
+            assert_eq!(Dir4::try_from(s), Ok(d));
+            assert_eq!(Dir4::try_from(d.to_char()), Ok(d));
+        }
+        assert!(Dir4::try_from('.').is_err());
+        assert_eq!("NE".parse(), Ok(Dir8::NorthEast));
+        assert_eq!("v".parse(), Ok(Dir8::South));
+        assert_eq!(Dir8::try_from('↙'), Ok(Dir8::SouthWest));
+        for d in Dir8::ALL {
+            assert_eq!(Dir8::try_from(d.to_char()), Ok(d));
+        }
+    }
+}
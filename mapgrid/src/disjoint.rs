@@ -0,0 +1,249 @@
+//! Union-find, and using it to track connectivity as a grid opens up.
+
+use std::ops::RangeInclusive;
+
+use crate::{add, Connectivity, Coord};
+
+/// Disjoint sets over `0..n`, with union by size and path halving
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// `n` singleton sets
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            sets: n,
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of distinct sets
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The representative of `x`'s set
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets containing `a` and `b`; false if they were already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set containing `x`
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+/// Which cells of a fixed area connect to which, as blocked cells are opened up one by one.
+///
+/// Everything starts blocked. Only removing obstacles is cheap, so to add obstacles
+/// instead, start from the end state and open them in reverse (see `first_blocking`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenCells {
+    bounds: [RangeInclusive<isize>; 2],
+    width: usize,
+    open: Vec<bool>,
+    sets: DisjointSet,
+    offsets: Vec<Coord>,
+}
+
+impl OpenCells {
+    pub fn new(bounds: [RangeInclusive<isize>; 2], connectivity: Connectivity) -> Self {
+        let width = bounds[0].clone().count();
+        let n = width * bounds[1].clone().count();
+        Self {
+            bounds,
+            width,
+            open: vec![false; n],
+            sets: DisjointSet::new(n),
+            offsets: connectivity.offsets(),
+        }
+    }
+
+    fn index(&self, [x, y]: &Coord) -> Option<usize> {
+        let [xs, ys] = &self.bounds;
+        (xs.contains(x) && ys.contains(y))
+            .then(|| (y - ys.start()) as usize * self.width + (x - xs.start()) as usize)
+    }
+
+    pub fn is_open(&self, coord: &Coord) -> bool {
+        self.index(coord).is_some_and(|i| self.open[i])
+    }
+
+    /// Open a cell, joining it to its open neighbours.
+    ///
+    /// Cells outside the area are ignored.
+    pub fn open(&mut self, coord: Coord) {
+        let Some(i) = self.index(&coord) else {
+            return;
+        };
+        self.open[i] = true;
+        for d in &self.offsets {
+            if let Some(j) = self.index(&add(coord, *d)) {
+                if self.open[j] {
+                    self.sets.union(i, j);
+                }
+            }
+        }
+    }
+
+    /// Is there a path of open cells from `a` to `b`?
+    pub fn connected(&mut self, a: &Coord, b: &Coord) -> bool {
+        match (self.index(a), self.index(b)) {
+            (Some(i), Some(j)) => self.open[i] && self.open[j] && self.sets.connected(i, j),
+            _ => false,
+        }
+    }
+}
+
+/// Dropping `obstacles` into `bounds` one at a time, which is the first to cut `a` off from `b`?
+///
+/// Returns its index, or `None` if no obstacle is to blame: either `a` and `b` are
+/// still connected at the end, or one of them is outside `bounds`, so they never were.
+/// Runs backwards from the final state, so it's near-linear rather than a search per obstacle.
+pub fn first_blocking(
+    bounds: [RangeInclusive<isize>; 2],
+    obstacles: &[Coord],
+    a: Coord,
+    b: Coord,
+    connectivity: Connectivity,
+) -> Option<usize> {
+    let mut grid = OpenCells::new(bounds.clone(), connectivity);
+
+    // a cell is blocked from its first appearance onwards
+    let mut first = vec![None; grid.open.len()];
+    for (n, o) in obstacles.iter().enumerate() {
+        if let Some(i) = grid.index(o) {
+            first[i].get_or_insert(n);
+        }
+    }
+
+    let [xs, ys] = bounds;
+    for y in ys {
+        for x in xs.clone() {
+            if grid.index(&[x, y]).is_some_and(|i| first[i].is_none()) {
+                grid.open([x, y]);
+            }
+        }
+    }
+    if grid.connected(&a, &b) {
+        return None;
+    }
+
+    for (n, o) in obstacles.iter().enumerate().rev() {
+        if grid.index(o).is_some_and(|i| first[i] == Some(n)) {
+            grid.open(*o);
+            if grid.connected(&a, &b) {
+                return Some(n);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_find() {
+        let mut s = DisjointSet::new(6);
+        assert!(s.union(0, 1));
+        assert!(s.union(2, 3));
+        assert!(!s.union(1, 0));
+        assert!(s.union(1, 3));
+        assert!(s.connected(0, 2));
+        assert!(!s.connected(0, 4));
+        assert_eq!(s.set_size(3), 4);
+        assert_eq!(s.sets(), 3);
+        assert_eq!(s.len(), 6);
+    }
+
+    #[test]
+    fn opening_up() {
+        let mut g = OpenCells::new([0..=2, 0..=0], Connectivity::Four);
+        g.open([0, 0]);
+        g.open([2, 0]);
+        assert!(!g.connected(&[0, 0], &[2, 0]));
+        g.open([1, 0]);
+        assert!(g.connected(&[0, 0], &[2, 0]));
+        assert!(!g.connected(&[0, 0], &[3, 0]));
+        g.open([9, 9]);
+        assert!(!g.is_open(&[9, 9]));
+    }
+
+    #[test]
+    fn blocking() {
+        // a wall down the middle, built from the bottom, with a repeat along the way
+        let wall = [[1, 2], [1, 1], [1, 2], [5, 5], [1, 0], [0, 0]];
+        let at = first_blocking([0..=2, 0..=2], &wall, [0, 2], [2, 0], Connectivity::Four);
+        assert_eq!(at, Some(4));
+
+        // diagonal gaps only block four-way movement
+        let diagonal = [[0, 2], [1, 1], [2, 0]];
+        let bounds = [0..=2, 0..=2];
+        assert_eq!(
+            first_blocking(
+                bounds.clone(),
+                &diagonal,
+                [0, 0],
+                [2, 2],
+                Connectivity::Four
+            ),
+            Some(2)
+        );
+        assert_eq!(
+            first_blocking(
+                bounds.clone(),
+                &diagonal,
+                [0, 0],
+                [2, 2],
+                Connectivity::Eight
+            ),
+            None
+        );
+
+        // outside the area, nothing connects in the first place
+        assert_eq!(
+            first_blocking(bounds, &diagonal, [0, 0], [3, 3], Connectivity::Four),
+            None
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Export grids as plain-text Netpbm (PBM/PGM/PPM) or SVG images.
+//!
+//! Each exporter takes a closure from a cell's value
+//! (or `None`, for absent cells within the bounds) to a pixel.
+
+use std::fmt::Write;
+
+use crate::{Cells, Colour, Grid};
+
+/// Red, green, blue
+pub type Rgb = [u8; 3];
+
+impl Colour {
+    /// Roughly the usual terminal palette
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [229, 229, 229],
+            Colour::Grey => [102, 102, 102],
+        }
+    }
+}
+
+/// Every pixel in reading order, plus the width and height
+fn pixels<G, V, P, F>(grid: &G, f: F) -> (usize, usize, Vec<P>)
+where
+    G: Grid<V> + Cells<V>,
+    F: Fn(Option<&V>) -> P,
+{
+    let [xs, ys] = grid.bounds();
+    let mut out = vec![];
+    for y in ys.clone() {
+        for x in xs.clone() {
+            out.push(f(grid.cell(&[x, y])));
+        }
+    }
+    (xs.count(), ys.count(), out)
+}
+
+/// Netpbm plain formats want lines of at most 70 characters
+fn push_wrapped<I: IntoIterator<Item = String>>(out: &mut String, tokens: I) {
+    let mut line = 0;
+    for t in tokens {
+        if line > 0 && line + 1 + t.len() > 70 {
+            out.push('\n');
+            line = 0;
+        } else if line > 0 {
+            out.push(' ');
+            line += 1;
+        }
+        line += t.len();
+        out.push_str(&t);
+    }
+    out.push('\n');
+}
+
+/// Black and white (P1); `ink` is true for black
+pub fn to_pbm<G, V, F>(grid: &G, ink: F) -> String
+where
+    G: Grid<V> + Cells<V>,
+    F: Fn(Option<&V>) -> bool,
+{
+    let (w, h, px) = pixels(grid, ink);
+    let mut out = format!("P1\n{w} {h}\n");
+    push_wrapped(&mut out, px.into_iter().map(|b| u8::from(b).to_string()));
+    out
+}
+
+/// Greyscale (P2), from 0 (black) to 255 (white)
+pub fn to_pgm<G, V, F>(grid: &G, shade: F) -> String
+where
+    G: Grid<V> + Cells<V>,
+    F: Fn(Option<&V>) -> u8,
+{
+    let (w, h, px) = pixels(grid, shade);
+    let mut out = format!("P2\n{w} {h}\n255\n");
+    push_wrapped(&mut out, px.into_iter().map(|v| v.to_string()));
+    out
+}
+
+/// Colour (P3)
+pub fn to_ppm<G, V, F>(grid: &G, colour: F) -> String
+where
+    G: Grid<V> + Cells<V>,
+    F: Fn(Option<&V>) -> Rgb,
+{
+    let (w, h, px) = pixels(grid, colour);
+    let mut out = format!("P3\n{w} {h}\n255\n");
+    push_wrapped(&mut out, px.into_iter().flatten().map(|v| v.to_string()));
+    out
+}
+
+/// One `scale`-pixel square per cell; cells mapped to `None` are left transparent
+pub fn to_svg<G, V, F>(grid: &G, scale: usize, colour: F) -> String
+where
+    G: Grid<V> + Cells<V>,
+    F: Fn(Option<&V>) -> Option<Rgb>,
+{
+    let (w, h, px) = pixels(grid, colour);
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+        w * scale,
+        h * scale
+    );
+    for (i, p) in px.into_iter().enumerate() {
+        if let Some([r, g, b]) = p {
+            let (x, y) = (i % w * scale, i / w * scale);
+            writeln!(
+                out,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{scale}\" height=\"{scale}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>"
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord, DenseGrid};
+    use std::collections::HashMap;
+
+    const DIGITS: &str = r"1.
+.2
+9.";
+
+    fn parse() -> HashMap<Coord, u8> {
+        <HashMap<Coord, u8> as Grid<u8>>::from_str_with(DIGITS, |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    #[test]
+    fn netpbm() {
+        let grid = parse();
+        assert_eq!(to_pbm(&grid, |v| v.is_some()), "P1\n2 3\n1 0 0 1 1 0\n");
+        assert_eq!(
+            to_pgm(&grid, |v| v.map_or(0, |d| d * 10)),
+            "P2\n2 3\n255\n10 0 0 20 90 0\n"
+        );
+        assert_eq!(
+            to_ppm(&grid, |v| if v.is_some() {
+                [255, 0, 0]
+            } else {
+                Colour::Black.rgb()
+            }),
+            "P3\n2 3\n255\n255 0 0 0 0 0 0 0 0 255 0 0 255 0 0 0 0 0\n"
+        );
+
+        // long rows are wrapped
+        let wide = DenseGrid::filled(50, 1, true);
+        let pbm = to_pbm(&wide, |v| v == Some(&true));
+        assert!(pbm.lines().all(|l| l.len() <= 70));
+        assert_eq!(pbm.lines().skip(2).flat_map(|l| l.split(' ')).count(), 50);
+    }
+
+    #[test]
+    fn svg() {
+        let grid = parse();
+        let svg = to_svg(&grid, 10, |v| v.map(|_| Colour::White.rgb()));
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"30\"")
+        );
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"10\" width=\"10\" height=\"10\" fill=\"#e5e5e5\"/>")
+        );
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -3,196 +3,157 @@ use std::{
     ops::RangeInclusive,
 };
 
-pub type Coord = [isize; 2];
-
-/// A Grid trait
-pub trait Grid<V = char> {
+mod anim;
+mod bitgrid;
+mod bounded;
+mod cells;
+mod dense;
+mod diff;
+mod dir;
+mod disjoint;
+mod image;
+mod markers;
+mod nd;
+mod parse;
+mod pattern;
+mod regions;
+mod render;
+pub mod search;
+mod shapes;
+mod tokens;
+mod torus;
+mod transform;
+mod vec2;
+
+pub use anim::{BadFrameRate, Frame, Recorder};
+pub use bitgrid::BitGrid;
+pub use bounded::Bounded;
+pub use cells::Cells;
+pub use dense::DenseGrid;
+pub use diff::{CellDiff, GridDiff};
+pub use dir::{Dir4, Dir8, ParseDirError};
+pub use disjoint::{first_blocking, DisjointSet, OpenCells};
+pub use image::{to_pbm, to_pgm, to_ppm, to_svg, Rgb};
+pub use markers::{parse_with_markers, take_markers, MarkerError};
+pub use nd::{neighbour_offsets, orthogonal_offsets};
+pub use parse::{ParseError, TryGrid};
+pub use pattern::{find_sequence, find_word, Template};
+pub use regions::{label_regions, Connectivity, Region, Regions};
+pub use render::{Colour, Layer, Pixel, Renderer};
+pub use shapes::{chebyshev_ball, manhattan_ball, manhattan_ring, ray};
+pub use tokens::{expand, parse_tokens, render_tokens, NoToken};
+pub use torus::Torus;
+pub use transform::{FromCells, Symmetry, Transform};
+pub use vec2::Vec2;
+
+/// A position: `[x, y]` by default, or with `N` axes
+pub type Coord<const N: usize = 2> = [isize; N];
+
+/// A Grid trait, in 2 dimensions unless `N` says otherwise.
+///
+/// Pictures are drawn on the x-y plane, so `from_str_with` and `visualise`
+/// won't compile with fewer than two axes:
+///
+/// ```compile_fail
+/// # use std::collections::HashMap;
+/// # use mapgrid::{Coord, Grid};
+/// let line: HashMap<Coord<1>, char> = Grid::from_str_with("ab", Some);
+/// ```
+pub trait Grid<V = char, const N: usize = 2> {
+    /// With more than two axes, the picture is the layer where the others are all 0
     fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> Self;
 
-    /// Returns [xmin..=xmax, ymin..=ymax]
-    fn bounds(&self) -> [RangeInclusive<isize>; 2];
+    /// Returns [xmin..=xmax, ymin..=ymax, ...]
+    fn bounds(&self) -> [RangeInclusive<isize>; N];
 
+    /// With more than two axes, each layer in turn, headed like `z=0, w=1`
     fn visualise(&self) -> String;
 }
 
-impl<V> Grid<V> for BTreeMap<Coord, V>
+impl<const N: usize, V> Grid<V, N> for BTreeMap<Coord<N>, V>
 where
     V: ToString,
 {
-    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> BTreeMap<Coord, V> {
-        let mut out = BTreeMap::new();
-
-        for (row, s) in input.lines().enumerate() {
-            for (col, cha) in s.chars().enumerate() {
-                if let Some(v) = f(cha) {
-                    out.insert([col as isize, row as isize], v);
-                }
-            }
-        }
-        out
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> BTreeMap<Coord<N>, V> {
+        nd::parse_layer(input, f).into_iter().collect()
     }
-    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
-        let xmax = self.iter().map(|(v, _)| v[0]).max().unwrap_or(0);
-        let ymax = self.iter().map(|(v, _)| v[1]).max().unwrap_or(0);
-        let xmin = self.iter().map(|(v, _)| v[0]).min().unwrap_or(0);
-        let ymin = self.iter().map(|(v, _)| v[1]).min().unwrap_or(0);
 
-        [xmin..=xmax, ymin..=ymax]
+    fn bounds(&self) -> [RangeInclusive<isize>; N] {
+        nd::bounds_of(self.keys())
     }
 
     /// Visualise the grid. Note that only the first character is used.
     fn visualise(&self) -> String {
-        let mut out = String::new();
-        let [xs, ys] = <BTreeMap<Coord, V> as Grid<V>>::bounds(self);
-
-        for y in ys {
-            for x in xs.clone() {
-                if let Some(v) = self.get(&[x, y]) {
-                    out.push(v.to_string().chars().nth(0).unwrap_or('#'));
-                } else {
-                    out.push('.');
-                }
-            }
-            out.push('\n');
-        }
-        out
+        nd::visualise_layers(self, Grid::bounds(self), first_char)
     }
 }
-impl<V> Grid<V> for BTreeSet<Coord> {
+
+impl<const N: usize, V> Grid<V, N> for BTreeSet<Coord<N>> {
     /// Note: the value of V is disregarded
-    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> BTreeSet<Coord> {
-        let mut out = BTreeSet::new();
-
-        for (row, s) in input.lines().enumerate() {
-            for (col, cha) in s.chars().enumerate() {
-                if f(cha).is_some() {
-                    out.insert([col as isize, row as isize]);
-                }
-            }
-        }
-        out
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> BTreeSet<Coord<N>> {
+        nd::parse_layer(input, f)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect()
     }
-    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
-        let xmax = self.iter().map(|v| v[0]).max().unwrap_or(0);
-        let ymax = self.iter().map(|v| v[1]).max().unwrap_or(0);
-        let xmin = self.iter().map(|v| v[0]).min().unwrap_or(0);
-        let ymin = self.iter().map(|v| v[1]).min().unwrap_or(0);
 
-        [xmin..=xmax, ymin..=ymax]
+    fn bounds(&self) -> [RangeInclusive<isize>; N] {
+        nd::bounds_of(self.iter())
     }
 
     fn visualise(&self) -> String {
-        let mut out = String::new();
-        let [xs, ys] = <BTreeSet<[isize; 2]> as Grid>::bounds(self);
-
-        for y in ys {
-            for x in xs.clone() {
-                if self.contains(&[x, y]) {
-                    out.push('#');
-                } else {
-                    out.push('.');
-                }
-            }
-            out.push('\n');
-        }
-        out
+        nd::visualise_layers(self, nd::bounds_of(self.iter()), |_| '#')
     }
 }
 
-impl<V> Grid<V> for HashSet<Coord> {
+impl<const N: usize, V> Grid<V, N> for HashSet<Coord<N>> {
     /// Note: the value of V is disregarded
-    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> HashSet<Coord> {
-        let mut out = HashSet::new();
-
-        for (row, s) in input.lines().enumerate() {
-            for (col, cha) in s.chars().enumerate() {
-                if f(cha).is_some() {
-                    out.insert([col as isize, row as isize]);
-                }
-            }
-        }
-        out
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> HashSet<Coord<N>> {
+        nd::parse_layer(input, f)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect()
     }
-    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
-        let xmax = self.iter().map(|v| v[0]).max().unwrap_or(0);
-        let ymax = self.iter().map(|v| v[1]).max().unwrap_or(0);
-        let xmin = self.iter().map(|v| v[0]).min().unwrap_or(0);
-        let ymin = self.iter().map(|v| v[1]).min().unwrap_or(0);
 
-        [xmin..=xmax, ymin..=ymax]
+    fn bounds(&self) -> [RangeInclusive<isize>; N] {
+        nd::bounds_of(self.iter())
     }
 
     fn visualise(&self) -> String {
-        let mut out = String::new();
-        let [xs, ys] = <HashSet<[isize; 2]> as Grid<V>>::bounds(self);
-
-        for y in ys {
-            for x in xs.clone() {
-                if self.contains(&[x, y]) {
-                    out.push('#');
-                } else {
-                    out.push('.');
-                }
-            }
-            out.push('\n');
-        }
-        out
+        nd::visualise_layers(self, nd::bounds_of(self.iter()), |_| '#')
     }
 }
 
-impl<V> Grid<V> for HashMap<Coord, V>
+impl<const N: usize, V> Grid<V, N> for HashMap<Coord<N>, V>
 where
     V: ToString,
 {
-    /// Note: the value of V is disregarded
-    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> HashMap<Coord, V> {
-        let mut out = HashMap::new();
-
-        for (row, s) in input.lines().enumerate() {
-            for (col, cha) in s.chars().enumerate() {
-                if let Some(v) = f(cha) {
-                    out.insert([col as isize, row as isize], v);
-                }
-            }
-        }
-        out
+    fn from_str_with<F: Fn(char) -> Option<V>>(input: &str, f: F) -> HashMap<Coord<N>, V> {
+        nd::parse_layer(input, f).into_iter().collect()
     }
-    fn bounds(&self) -> [RangeInclusive<isize>; 2] {
-        let xmax = self.iter().map(|(v, _)| v[0]).max().unwrap_or(0);
-        let ymax = self.iter().map(|(v, _)| v[1]).max().unwrap_or(0);
-        let xmin = self.iter().map(|(v, _)| v[0]).min().unwrap_or(0);
-        let ymin = self.iter().map(|(v, _)| v[1]).min().unwrap_or(0);
 
-        [xmin..=xmax, ymin..=ymax]
+    fn bounds(&self) -> [RangeInclusive<isize>; N] {
+        nd::bounds_of(self.keys())
     }
 
     /// Visualise the grid. Note that only the first character is used.
     fn visualise(&self) -> String {
-        let mut out = String::new();
-        let [xs, ys] = <HashMap<Coord, V> as Grid<V>>::bounds(self);
-
-        for y in ys {
-            for x in xs.clone() {
-                if let Some(v) = self.get(&[x, y]) {
-                    out.push(v.to_string().chars().nth(0).unwrap_or('#'));
-                } else {
-                    out.push('.');
-                }
-            }
-            out.push('\n');
-        }
-        out
+        nd::visualise_layers(self, Grid::bounds(self), first_char)
     }
 }
 
+fn first_char<V: ToString>(v: &V) -> char {
+    v.to_string().chars().next().unwrap_or('#')
+}
+
 /// Elementwise add
-pub fn add(a: Coord, b: Coord) -> Coord {
-    [a[0] + b[0], a[1] + b[1]]
+pub fn add<const N: usize>(a: Coord<N>, b: Coord<N>) -> Coord<N> {
+    std::array::from_fn(|i| a[i] + b[i])
 }
 
 /// Elementwise subtract
-pub fn subtract(a: Coord, b: Coord) -> Coord {
-    [a[0] - b[0], a[1] - b[1]]
+pub fn subtract<const N: usize>(a: Coord<N>, b: Coord<N>) -> Coord<N> {
+    std::array::from_fn(|i| a[i] - b[i])
 }
 
 #[cfg(test)]
@@ -0,0 +1,116 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{Coord, Grid};
+
+/// A marker that wasn't found exactly once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkerError {
+    Missing(char),
+    Duplicate { marker: char, at: [Coord; 2] },
+}
+
+impl fmt::Display for MarkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkerError::Missing(m) => write!(f, "marker {m:?} not found"),
+            MarkerError::Duplicate { marker, at } => {
+                write!(
+                    f,
+                    "marker {marker:?} found at both {:?} and {:?}",
+                    at[0], at[1]
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MarkerError {}
+
+/// Find each of `markers`, which must appear exactly once,
+/// and replace it with `floor`.
+///
+/// Returns the text without markers, so it can be parsed however you like,
+/// and where each marker was (in `from_str_with` coordinates).
+pub fn take_markers(
+    input: &str,
+    markers: &[char],
+    floor: char,
+) -> Result<(String, HashMap<char, Coord>), MarkerError> {
+    let mut found: HashMap<char, Coord> = HashMap::new();
+    let mut out = String::with_capacity(input.len());
+
+    for (row, s) in input.split_inclusive('\n').enumerate() {
+        for (col, cha) in s.chars().enumerate() {
+            if markers.contains(&cha) {
+                let here = [col as isize, row as isize];
+                if let Some(first) = found.insert(cha, here) {
+                    return Err(MarkerError::Duplicate {
+                        marker: cha,
+                        at: [first, here],
+                    });
+                }
+                out.push(floor);
+            } else {
+                out.push(cha);
+            }
+        }
+    }
+
+    match markers.iter().find(|m| !found.contains_key(m)) {
+        Some(m) => Err(MarkerError::Missing(*m)),
+        None => Ok((out, found)),
+    }
+}
+
+/// `take_markers`, then `Grid::from_str_with`
+pub fn parse_with_markers<G, V, F>(
+    input: &str,
+    markers: &[char],
+    floor: char,
+    parser: F,
+) -> Result<(G, HashMap<char, Coord>), MarkerError>
+where
+    G: Grid<V>,
+    F: Fn(char) -> Option<V>,
+{
+    let (text, found) = take_markers(input, markers, floor)?;
+    Ok((G::from_str_with(&text, parser), found))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const MAZE: &str = r"#####
+#S.E#
+#####
+";
+
+    #[test]
+    fn markers() {
+        let (text, found) = take_markers(MAZE, &['S', 'E'], '.').unwrap();
+        assert_eq!(text, "#####\n#...#\n#####\n");
+        assert_eq!(found, HashMap::from([('S', [1, 1]), ('E', [3, 1])]));
+
+        let (walls, found): (HashSet<Coord>, _) =
+            parse_with_markers(MAZE, &['E'], '.', |c| (c == '#').then_some(())).unwrap();
+        assert_eq!(walls.len(), 12);
+        assert_eq!(found[&'E'], [3, 1]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            take_markers(MAZE, &['S', '@'], '.'),
+            Err(MarkerError::Missing('@'))
+        );
+        assert_eq!(
+            take_markers("S.\n.S", &['S'], '.'),
+            Err(MarkerError::Duplicate {
+                marker: 'S',
+                at: [[0, 0], [1, 1]]
+            })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Grids of any number of dimensions, for 3D/4D cellular automata and voxels.
+//!
+//! The first two axes are x and y, as in 2D; any further axes pick out a layer.
+//! The map and set impls of `Grid`, `Cells` and `FromCells` work with any `N` axes.
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use crate::{Cells, Coord, Renderer};
+
+/// `[x, y]` on the layer where every other axis is 0.
+///
+/// Fails to compile if `N < 2`.
+pub(crate) fn on_layer<const N: usize>(x: isize, y: isize) -> Coord<N> {
+    const { assert!(N >= 2, "grids need at least an x and a y axis") };
+    let mut coord = [0; N];
+    coord[0] = x;
+    coord[1] = y;
+    coord
+}
+
+/// The cells of a picture, as `Grid::from_str_with` reads them
+pub(crate) fn parse_layer<const N: usize, V, F>(input: &str, f: F) -> Vec<(Coord<N>, V)>
+where
+    F: Fn(char) -> Option<V>,
+{
+    let mut out = vec![];
+    for (row, s) in input.lines().enumerate() {
+        for (col, cha) in s.chars().enumerate() {
+            if let Some(v) = f(cha) {
+                out.push((on_layer(col as isize, row as isize), v));
+            }
+        }
+    }
+    out
+}
+
+/// `[min..=max]` along each axis, or `0..=0` if there are no coordinates
+pub(crate) fn bounds_of<'a, const N: usize, I>(coords: I) -> [RangeInclusive<isize>; N]
+where
+    I: Iterator<Item = &'a Coord<N>> + Clone,
+{
+    std::array::from_fn(|i| {
+        let min = coords.clone().map(|k| k[i]).min().unwrap_or(0);
+        let max = coords.clone().map(|k| k[i]).max().unwrap_or(0);
+        min..=max
+    })
+}
+
+/// Every layer within `bounds`, one after another, each headed like `z=0, w=1`.
+///
+/// Layers are all drawn over the same x and y range, with `.` for absent cells.
+/// In 2D there's just the one layer, and no heading. Fails to compile if `N < 2`.
+pub(crate) fn visualise_layers<const N: usize, V, G, F>(
+    grid: &G,
+    bounds: [RangeInclusive<isize>; N],
+    glyph: F,
+) -> String
+where
+    G: Cells<V, N>,
+    F: Fn(&V) -> char,
+{
+    const { assert!(N >= 2, "grids need at least an x and a y axis") };
+    let [xs, ys] = [bounds[0].clone(), bounds[1].clone()];
+
+    let mut out = String::new();
+    for rest in points(&bounds[2..]) {
+        let heading: Vec<String> = rest
+            .iter()
+            .enumerate()
+            .map(|(i, v)| format!("{}={v}", axis_name(i + 2)))
+            .collect();
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        if !heading.is_empty() {
+            out.push_str(&heading.join(", "));
+            out.push('\n');
+        }
+        let layer: HashMap<Coord, char> = grid
+            .cells()
+            .filter(|(k, _)| k[2..] == *rest)
+            .map(|(k, v)| ([k[0], k[1]], glyph(v)))
+            .collect();
+        out.push_str(
+            &Renderer::with_glyphs(&layer, |c| *c)
+                .area(xs.clone(), ys.clone())
+                .plain()
+                .render(),
+        );
+    }
+    out
+}
+
+/// The `2N` unit steps along a single axis
+pub fn orthogonal_offsets<const N: usize>() -> Vec<Coord<N>> {
+    (0..N)
+        .flat_map(|i| {
+            [-1, 1].map(|d| {
+                let mut c = [0; N];
+                c[i] = d;
+                c
+            })
+        })
+        .collect()
+}
+
+/// The `3^N - 1` steps to every surrounding cell, diagonals included
+pub fn neighbour_offsets<const N: usize>() -> Vec<Coord<N>> {
+    points(&vec![-1..=1; N])
+        .into_iter()
+        .filter(|p| p.iter().any(|&d| d != 0))
+        .map(|p| std::array::from_fn(|i| p[i]))
+        .collect()
+}
+
+/// Every point within `bounds`, first axis fastest
+fn points(bounds: &[RangeInclusive<isize>]) -> Vec<Vec<isize>> {
+    let mut out = vec![vec![]];
+    for axis in bounds {
+        out = axis
+            .clone()
+            .flat_map(|v| {
+                out.iter().map(move |p| {
+                    let mut p = p.clone();
+                    p.push(v);
+                    p
+                })
+            })
+            .collect();
+    }
+    out
+}
+
+fn axis_name(i: usize) -> String {
+    match i {
+        0 => "x".into(),
+        1 => "y".into(),
+        2 => "z".into(),
+        3 => "w".into(),
+        i => format!("axis{i}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{add, FromCells, Grid};
+    use std::collections::{BTreeMap, HashSet};
+
+    const GLIDER: &str = r".#.
+..#
+###";
+
+    /// One round of the "Conway Cubes" rules
+    fn step<const N: usize>(active: &HashSet<Coord<N>>) -> HashSet<Coord<N>> {
+        let offsets = neighbour_offsets::<N>();
+        let candidates: HashSet<Coord<N>> = active
+            .iter()
+            .flat_map(|&c| offsets.iter().map(move |&d| add(c, d)))
+            .chain(active.iter().copied())
+            .collect();
+        candidates
+            .into_iter()
+            .filter(|&c| {
+                let n = active.neighbours_with(c, offsets.clone()).count();
+                n == 3 || (n == 2 && active.contains(&c))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(orthogonal_offsets::<3>().len(), 6);
+        assert_eq!(neighbour_offsets::<3>().len(), 26);
+        assert_eq!(neighbour_offsets::<4>().len(), 80);
+        assert_eq!(neighbour_offsets::<2>().len(), 8);
+    }
+
+    #[test]
+    fn conway_cubes() {
+        let mut cubes: HashSet<Coord<3>> =
+            Grid::<(), 3>::from_str_with(GLIDER, |c| (c == '#').then_some(()));
+        assert_eq!(cubes.len(), 5);
+        assert_eq!(Grid::<(), 3>::bounds(&cubes), [0..=2, 0..=2, 0..=0]);
+        assert_eq!(cubes.neighbours_orthogonal([1, 1, 0]).count(), 3);
+
+        cubes = step(&cubes);
+        assert_eq!(cubes.len(), 11);
+        assert_eq!(
+            Grid::<(), 3>::visualise(&cubes),
+            "z=-1\n#..\n..#\n.#.\n\nz=0\n#.#\n.##\n.#.\n\nz=1\n#..\n..#\n.#.\n"
+        );
+        let middle: HashSet<Coord> = cubes.layer(&[0]).into_keys().collect();
+        assert_eq!(
+            <HashSet<Coord> as Grid>::visualise(&middle),
+            "#.#\n.##\n.#.\n"
+        );
+
+        for _ in 1..6 {
+            cubes = step(&cubes);
+        }
+        assert_eq!(cubes.len(), 112);
+
+        let mut hyper: HashSet<Coord<4>> =
+            Grid::<(), 4>::from_str_with(GLIDER, |c| (c == '#').then_some(()));
+        for _ in 0..6 {
+            hyper = step(&hyper);
+        }
+        assert_eq!(hyper.len(), 848);
+    }
+
+    #[test]
+    fn maps() {
+        let voxels: BTreeMap<Coord<3>, u8> =
+            FromCells::from_cells([0..=0, 0..=0, 0..=0], [([0, 0, 0], 1), ([2, 1, 5], 7)]);
+        assert_eq!(voxels.cell(&[2, 1, 5]), Some(&7));
+        assert_eq!(voxels.bounds(), [0..=2, 0..=1, 0..=5]);
+        assert_eq!(voxels.layer(&[5]), HashMap::from([([2, 1], 7)]));
+        assert_eq!(voxels.neighbours_all([1, 1, 4]).count(), 1);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{error::Error, fmt};
+
+use crate::{nd::on_layer, FromCells};
+
+/// Why `try_from_str_with` failed. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError<E> {
+    /// The parser rejected a character
+    Cell {
+        line: usize,
+        column: usize,
+        found: char,
+        source: E,
+    },
+    /// A line wasn't the same length as the first
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Cell {
+                line,
+                column,
+                found,
+                source,
+            } => write!(
+                f,
+                "line {line}, column {column}: bad cell {found:?}: {source}"
+            ),
+            ParseError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} characters long, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Cell { source, .. } => Some(source),
+            ParseError::Ragged { .. } => None,
+        }
+    }
+}
+
+/// Parsing that can fail, for any grid that can be built from its cells
+pub trait TryGrid<V, const N: usize = 2>: FromCells<V, N> {
+    /// Like `Grid::from_str_with`, but `parser` can reject characters,
+    /// and every line must be the same length.
+    ///
+    /// `Ok(None)` is still an empty cell. With more than two axes,
+    /// the picture is the layer where the others are all 0.
+    fn try_from_str_with<F, E>(input: &str, parser: F) -> Result<Self, ParseError<E>>
+    where
+        F: Fn(char) -> Result<Option<V>, E>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, s) in input.lines().enumerate() {
+            let len = s.chars().count();
+            let expected = *width.get_or_insert(len);
+            if len != expected {
+                return Err(ParseError::Ragged {
+                    line: row + 1,
+                    expected,
+                    found: len,
+                });
+            }
+
+            for (col, cha) in s.chars().enumerate() {
+                match parser(cha) {
+                    Ok(Some(v)) => cells.push((on_layer(col as isize, row as isize), v)),
+                    Ok(None) => (),
+                    Err(source) => {
+                        return Err(ParseError::Cell {
+                            line: row + 1,
+                            column: col + 1,
+                            found: cha,
+                            source,
+                        })
+                    }
+                }
+            }
+            height = row + 1;
+        }
+
+        let width = width.unwrap_or(0) as isize;
+        let mut bounds = std::array::from_fn(|_| 0..=0);
+        bounds[0] = 0..=width - 1;
+        bounds[1] = 0..=height as isize - 1;
+        Ok(Self::from_cells(bounds, cells))
+    }
+}
+
+impl<G, V, const N: usize> TryGrid<V, N> for G where G: FromCells<V, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord, DenseGrid};
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    fn digit(c: char) -> Result<Option<u32>, String> {
+        match c {
+            '.' => Ok(None),
+            c => c.to_digit(10).map(Some).ok_or("not a digit".to_string()),
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        let g = HashMap::<Coord, u32>::try_from_str_with("1.\n.2", digit).unwrap();
+        assert_eq!(g, HashMap::from([([0, 0], 1), ([1, 1], 2)]));
+
+        // empty edges are kept by dense grids
+        let d = DenseGrid::<u32>::try_from_str_with("..\n.2\n..", digit).unwrap();
+        assert_eq!((d.width(), d.height()), (2, 3));
+
+        let cubes = HashSet::<Coord<3>>::try_from_str_with(".#\n#.", |c| {
+            Ok::<_, ()>((c == '#').then_some(()))
+        })
+        .unwrap();
+        assert_eq!(cubes, HashSet::from([[1, 0, 0], [0, 1, 0]]));
+    }
+
+    #[test]
+    fn errors() {
+        let e = HashMap::<Coord, u32>::try_from_str_with("12\n3x", digit).unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::Cell {
+                line: 2,
+                column: 2,
+                found: 'x',
+                source: "not a digit".to_string()
+            }
+        );
+        assert_eq!(e.to_string(), "line 2, column 2: bad cell 'x': not a digit");
+
+        let e = BTreeSet::<Coord>::try_from_str_with("...\n..\n...", |_| Ok::<_, ()>(Some(())))
+            .unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+}
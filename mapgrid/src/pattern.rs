@@ -0,0 +1,221 @@
+use crate::{add, Cells, Coord, Dir8, Symmetry};
+
+/// Every place `needle` can be read in a straight line:
+/// the coordinate of its first element, and the direction it runs in.
+///
+/// Sorted by start coordinate (in reading order), then direction.
+/// A single-element needle has no direction, so each match is reported once,
+/// with the first of `Dir8::ALL`.
+pub fn find_sequence<G, V>(grid: &G, needle: &[V]) -> Vec<(Coord, Dir8)>
+where
+    G: Cells<V>,
+    V: PartialEq,
+{
+    let Some(first) = needle.first() else {
+        return vec![];
+    };
+
+    let mut out = vec![];
+    for (start, v) in grid.cells() {
+        if v != first {
+            continue;
+        }
+        if needle.len() == 1 {
+            out.push((start, Dir8::ALL[0]));
+            continue;
+        }
+        for dir in Dir8::ALL {
+            let mut here = start;
+            let found = needle[1..].iter().all(|n| {
+                here = add(here, dir.to_coord());
+                grid.cell(&here) == Some(n)
+            });
+            if found {
+                out.push((start, dir));
+            }
+        }
+    }
+    out.sort_by_key(|([x, y], d)| (*y, *x, *d));
+    out
+}
+
+/// `find_sequence` for the characters of a string
+pub fn find_word<G>(grid: &G, word: &str) -> Vec<(Coord, Dir8)>
+where
+    G: Cells<char>,
+{
+    find_sequence(grid, &word.chars().collect::<Vec<_>>())
+}
+
+/// A small rectangular shape to look for, which may contain wildcards
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template<V> {
+    /// Offsets from the top-left corner, in reading order. Wildcards are absent.
+    cells: Vec<(Coord, V)>,
+    width: isize,
+    height: isize,
+}
+
+impl<V> Template<V> {
+    /// Like `Grid::from_str_with`; cells that parse to `None` match anything
+    pub fn from_str_with<F>(s: &str, parser: F) -> Self
+    where
+        F: Fn(char) -> Option<V>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            height = y as isize + 1;
+            for (x, c) in line.chars().enumerate() {
+                width = width.max(x as isize + 1);
+                if let Some(v) = parser(c) {
+                    cells.push(([x as isize, y as isize], v));
+                }
+            }
+        }
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    /// The template rotated and/or reflected
+    pub fn apply(&self, symmetry: Symmetry) -> Self
+    where
+        V: Clone,
+    {
+        let mut cells: Vec<(Coord, V)> = self
+            .cells
+            .iter()
+            .map(|(k, v)| (symmetry.apply(*k, self.width, self.height), v.clone()))
+            .collect();
+        cells.sort_by_key(|([x, y], _)| (*y, *x));
+
+        let (width, height) = if symmetry.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Does the template match with its top-left corner at `anchor`?
+    pub fn matches_at<G>(&self, grid: &G, anchor: Coord) -> bool
+    where
+        G: Cells<V>,
+        V: PartialEq,
+    {
+        self.cells
+            .iter()
+            .all(|(k, v)| grid.cell(&add(anchor, *k)) == Some(v))
+    }
+
+    /// Anchors (top-left corners) of every match, in reading order.
+    ///
+    /// A template that is all wildcards matches nowhere.
+    pub fn find<G>(&self, grid: &G) -> Vec<Coord>
+    where
+        G: Cells<V>,
+        V: PartialEq,
+    {
+        let Some(([fx, fy], first)) = self.cells.first() else {
+            return vec![];
+        };
+
+        let mut out: Vec<Coord> = grid
+            .cells()
+            .filter(|(_, v)| *v == first)
+            .map(|([x, y], _)| [x - fx, y - fy])
+            .filter(|anchor| self.matches_at(grid, *anchor))
+            .collect();
+        out.sort_by_key(|[x, y]| (*y, *x));
+        out
+    }
+
+    /// Like `find`, but also trying every distinct rotation and reflection.
+    ///
+    /// Symmetries that leave the template unchanged are only tried once,
+    /// so each match is reported once.
+    pub fn find_symmetric<G>(&self, grid: &G) -> Vec<(Coord, Symmetry)>
+    where
+        G: Cells<V>,
+        V: PartialEq + Clone,
+    {
+        let mut orientations: Vec<(Symmetry, Self)> = vec![];
+        for s in Symmetry::ALL {
+            let t = self.apply(s);
+            if !orientations.iter().any(|(_, o)| *o == t) {
+                orientations.push((s, t));
+            }
+        }
+
+        let mut out: Vec<(Coord, Symmetry)> = orientations
+            .iter()
+            .flat_map(|(s, t)| t.find(grid).into_iter().map(|a| (a, *s)))
+            .collect();
+        out.sort_by_key(|([x, y], s)| (*y, *x, *s as usize));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+    use std::collections::HashMap;
+
+    const WORDS: &str = r"XMAS.
+MM.A.
+A.A..
+SM.S.";
+
+    fn parse(s: &str) -> HashMap<Coord, char> {
+        <HashMap<Coord, char> as Grid>::from_str_with(s, Some)
+    }
+
+    #[test]
+    fn words() {
+        let grid = parse(WORDS);
+        assert_eq!(
+            find_word(&grid, "XMAS"),
+            [
+                ([0, 0], Dir8::East),
+                ([0, 0], Dir8::SouthEast),
+                ([0, 0], Dir8::South)
+            ]
+        );
+        assert_eq!(find_word(&grid, "SAM").len(), 3);
+        assert_eq!(find_word(&grid, "A").len(), 4);
+        assert!(find_word(&grid, "").is_empty());
+    }
+
+    #[test]
+    fn templates() {
+        let grid = parse("M.S.\n.A..\nMMSM\n..A.\n.S.S");
+        let x_mas = Template::from_str_with("M.S\n.A.\nM.S", |c| (c != '.').then_some(c));
+
+        assert_eq!(x_mas.find(&grid), [[0, 0]]);
+        assert!(x_mas.matches_at(&grid, [0, 0]));
+        assert!(!x_mas.matches_at(&grid, [1, 2]));
+
+        let found = x_mas.find_symmetric(&grid);
+        assert_eq!(
+            found,
+            [([0, 0], Symmetry::Identity), ([1, 2], Symmetry::RotateCw)]
+        );
+    }
+}
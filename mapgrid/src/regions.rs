@@ -0,0 +1,264 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use crate::{add, Cells, Coord, Dir4, Dir8};
+
+/// Which cells count as touching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Orthogonal neighbours only
+    Four,
+    /// Orthogonal and diagonal neighbours
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(self) -> Vec<Coord> {
+        match self {
+            Connectivity::Four => Dir4::ALL.map(Dir4::to_coord).to_vec(),
+            Connectivity::Eight => Dir8::ALL.map(Dir8::to_coord).to_vec(),
+        }
+    }
+
+    /// The connectivity to use for the background, so that
+    /// regions and the gaps between them don't cross each other
+    pub fn dual(self) -> Self {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// A connected set of cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub cells: HashSet<Coord>,
+    pub connectivity: Connectivity,
+}
+
+/// The result of `label_regions`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    /// Region id (an index into `regions`) for every cell
+    pub labels: HashMap<Coord, usize>,
+    /// Numbered in reading order of their first cell
+    pub regions: Vec<Region>,
+}
+
+/// Split a grid into connected regions of equal value
+pub fn label_regions<G, V>(grid: &G, connectivity: Connectivity) -> Regions
+where
+    G: Cells<V>,
+    V: PartialEq,
+{
+    let offsets = connectivity.offsets();
+    let mut labels: HashMap<Coord, usize> = HashMap::new();
+    let mut regions = vec![];
+
+    let mut coords: Vec<Coord> = grid.cells().map(|(k, _)| k).collect();
+    coords.sort_by_key(|[x, y]| (*y, *x));
+
+    for coord in coords {
+        if labels.contains_key(&coord) {
+            continue;
+        }
+        let id = regions.len();
+        let value = grid.cell(&coord);
+        let mut cells = HashSet::from([coord]);
+        let mut queue = vec![coord];
+        labels.insert(coord, id);
+
+        while let Some(this) = queue.pop() {
+            for (next, v) in grid.neighbours_with(this, offsets.iter().copied()) {
+                if Some(v) == value && !labels.contains_key(&next) {
+                    labels.insert(next, id);
+                    cells.insert(next);
+                    queue.push(next);
+                }
+            }
+        }
+
+        regions.push(Region {
+            cells,
+            connectivity,
+        });
+    }
+
+    Regions { labels, regions }
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of unit edges between the region and anything else
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|c| {
+                Dir4::ALL
+                    .iter()
+                    .filter(|d| !self.cells.contains(&add(*c, d.to_coord())))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of corners on the boundary, including around holes.
+    ///
+    /// Panics unless the region was labelled with `Connectivity::Four`:
+    /// cells that only touch diagonally don't share a boundary to count along.
+    pub fn corners(&self) -> usize {
+        assert_eq!(
+            self.connectivity,
+            Connectivity::Four,
+            "corners are only counted for 4-connected regions"
+        );
+        let mut out = 0;
+        for c in &self.cells {
+            for [dx, dy] in [[1, 1], [1, -1], [-1, 1], [-1, -1]] {
+                let side_x = self.cells.contains(&add(*c, [dx, 0]));
+                let side_y = self.cells.contains(&add(*c, [0, dy]));
+                let diagonal = self.cells.contains(&add(*c, [dx, dy]));
+
+                // convex, or concave
+                if (!side_x && !side_y) || (side_x && side_y && !diagonal) {
+                    out += 1;
+                }
+            }
+        }
+        out
+    }
+
+    /// Number of straight sides on the boundary, including around holes.
+    ///
+    /// Every side ends in a corner, so this is the same as `corners` (and panics likewise).
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Returns [xmin..=xmax, ymin..=ymax]
+    pub fn bounds(&self) -> [RangeInclusive<isize>; 2] {
+        let xmax = self.cells.iter().map(|v| v[0]).max().unwrap_or(0);
+        let ymax = self.cells.iter().map(|v| v[1]).max().unwrap_or(0);
+        let xmin = self.cells.iter().map(|v| v[0]).min().unwrap_or(0);
+        let ymin = self.cells.iter().map(|v| v[1]).min().unwrap_or(0);
+
+        [xmin..=xmax, ymin..=ymax]
+    }
+
+    /// Number of separate gaps entirely enclosed by the region
+    pub fn holes(&self) -> usize {
+        let [xs, ys] = self.bounds();
+        let (xs, ys) = (
+            (xs.start() - 1)..=(xs.end() + 1),
+            (ys.start() - 1)..=(ys.end() + 1),
+        );
+        let in_box = |c: &Coord| xs.contains(&c[0]) && ys.contains(&c[1]);
+        let offsets = self.connectivity.dual().offsets();
+
+        let mut seen: HashSet<Coord> = HashSet::new();
+        let flood = |start: Coord, seen: &mut HashSet<Coord>| {
+            let mut queue = vec![start];
+            seen.insert(start);
+            while let Some(this) = queue.pop() {
+                for d in &offsets {
+                    let next = add(this, *d);
+                    if in_box(&next) && !self.cells.contains(&next) && seen.insert(next) {
+                        queue.push(next);
+                    }
+                }
+            }
+        };
+
+        // the expanded box's corner is always outside
+        flood([*xs.start(), *ys.start()], &mut seen);
+
+        let mut holes = 0;
+        for y in ys.clone() {
+            for x in xs.clone() {
+                if !self.cells.contains(&[x, y]) && !seen.contains(&[x, y]) {
+                    holes += 1;
+                    flood([x, y], &mut seen);
+                }
+            }
+        }
+        holes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const FIELD: &str = r"AAAA
+BBCD
+BBCC
+EEEC";
+
+    const HOLEY: &str = r"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+    fn parse(s: &str) -> HashMap<Coord, char> {
+        <HashMap<Coord, char> as Grid>::from_str_with(s, Some)
+    }
+
+    #[test]
+    fn fencing() {
+        let r = label_regions(&parse(FIELD), Connectivity::Four);
+
+        assert_eq!(r.regions.len(), 5);
+        assert_eq!(r.labels[&[0, 0]], 0);
+        assert_eq!(r.labels[&[0, 1]], 1);
+
+        let stats: Vec<_> = r
+            .regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.sides()))
+            .collect();
+        assert_eq!(
+            stats,
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(r.regions[2].bounds(), [2..=3, 1..=3]);
+    }
+
+    #[test]
+    fn holes() {
+        let r = label_regions(&parse(HOLEY), Connectivity::Four);
+
+        assert_eq!(r.regions.len(), 5);
+        let outer = &r.regions[0];
+        assert_eq!(outer.area(), 21);
+        assert_eq!(outer.holes(), 4);
+        assert_eq!(outer.perimeter(), 36);
+        assert_eq!(outer.sides(), 20);
+        assert_eq!(r.regions[1].holes(), 0);
+    }
+
+    #[test]
+    fn diagonals() {
+        let grid = parse("X.\n.X");
+        assert_eq!(label_regions(&grid, Connectivity::Four).regions.len(), 4);
+
+        let r = label_regions(&grid, Connectivity::Eight);
+        assert_eq!(r.regions.len(), 2);
+        assert_eq!(r.regions[0].area(), 2);
+        assert_eq!(r.regions[0].holes(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "4-connected")]
+    fn diagonal_sides() {
+        let r = label_regions(&parse("X.\n.X"), Connectivity::Eight);
+        r.regions[0].sides();
+    }
+}
@@ -0,0 +1,338 @@
+use std::{collections::HashMap, fmt, ops::RangeInclusive};
+
+use crate::{Cells, Coord, DenseGrid};
+
+/// The eight standard ANSI foreground colours, plus grey
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// The SGR parameter for this foreground colour
+    pub fn ansi_code(self) -> u8 {
+        match self {
+            Colour::Black => 30,
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+        }
+    }
+
+    /// `s` wrapped in this colour's escape codes
+    pub fn paint(self, s: &str) -> String {
+        format!("\x1b[{}m{s}\x1b[0m", self.ansi_code())
+    }
+}
+
+/// One cell as drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub colour: Option<Colour>,
+}
+
+impl fmt::Display for Pixel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.glyph)
+    }
+}
+
+/// A set of cells to draw over the base grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    /// `None` keeps whatever glyph is underneath
+    cells: HashMap<Coord, Option<char>>,
+    colour: Option<Colour>,
+}
+
+impl Layer {
+    /// Cells that keep their underlying glyph until `glyph` is set
+    pub fn new<I: IntoIterator<Item = Coord>>(cells: I) -> Self {
+        Self {
+            cells: cells.into_iter().map(|k| (k, None)).collect(),
+            colour: None,
+        }
+    }
+
+    /// Cells with a glyph each, e.g. direction arrows along a path
+    pub fn glyphs<I: IntoIterator<Item = (Coord, char)>>(cells: I) -> Self {
+        Self {
+            cells: cells.into_iter().map(|(k, c)| (k, Some(c))).collect(),
+            colour: None,
+        }
+    }
+
+    /// Draw every cell in the layer as `glyph`
+    pub fn glyph(mut self, glyph: char) -> Self {
+        self.cells.values_mut().for_each(|g| *g = Some(glyph));
+        self
+    }
+
+    pub fn colour(mut self, colour: Colour) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+}
+
+/// Draws a grid, with overlays, for the terminal.
+///
+/// Later layers are drawn over earlier ones.
+/// The drawn area covers the base grid and every layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Renderer {
+    base: HashMap<Coord, char>,
+    base_colour: Option<Colour>,
+    layers: Vec<Layer>,
+    background: char,
+    area: Option<[RangeInclusive<isize>; 2]>,
+    axes: bool,
+    colour: bool,
+}
+
+impl Renderer {
+    /// Base glyphs are the first character of each value's `to_string()`
+    pub fn new<G, V>(grid: &G) -> Self
+    where
+        G: Cells<V>,
+        V: ToString,
+    {
+        Self::with_glyphs(grid, |v| v.to_string().chars().next().unwrap_or('#'))
+    }
+
+    /// Base glyphs are chosen by `glyph`
+    pub fn with_glyphs<G, V, F>(grid: &G, glyph: F) -> Self
+    where
+        G: Cells<V>,
+        F: Fn(&V) -> char,
+    {
+        Self {
+            base: grid.cells().map(|(k, v)| (k, glyph(v))).collect(),
+            base_colour: None,
+            layers: vec![],
+            background: '.',
+            area: None,
+            axes: false,
+            colour: false,
+        }
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Colour for the base grid's own glyphs
+    pub fn base_colour(mut self, colour: Colour) -> Self {
+        self.base_colour = Some(colour);
+        self
+    }
+
+    /// Glyph for cells that nothing covers; `.` by default
+    pub fn background(mut self, background: char) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Draw exactly `[xs, ys]`, rather than whatever the grid and layers cover
+    pub fn area(mut self, xs: RangeInclusive<isize>, ys: RangeInclusive<isize>) -> Self {
+        self.area = Some([xs, ys]);
+        self
+    }
+
+    /// Label rows and columns with their coordinates
+    pub fn axes(mut self, axes: bool) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Whether to emit ANSI escape codes; off by default, so logs and files stay plain.
+    ///
+    /// Turn it on for output that's going straight to a terminal.
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    /// Shorthand for `colour(false)`
+    pub fn plain(self) -> Self {
+        self.colour(false)
+    }
+
+    /// Returns ([xmin, ymin], [xmax, ymax]) over the base and all layers, unless `area` is set
+    fn extent(&self) -> ([isize; 2], [isize; 2]) {
+        if let Some([xs, ys]) = &self.area {
+            return ([*xs.start(), *ys.start()], [*xs.end(), *ys.end()]);
+        }
+        let coords = self
+            .base
+            .keys()
+            .chain(self.layers.iter().flat_map(|l| l.cells.keys()));
+        let mut lo = [isize::MAX; 2];
+        let mut hi = [isize::MIN; 2];
+        for c in coords {
+            for i in 0..2 {
+                lo[i] = lo[i].min(c[i]);
+                hi[i] = hi[i].max(c[i]);
+            }
+        }
+        if lo[0] > hi[0] {
+            ([0, 0], [0, 0])
+        } else {
+            (lo, hi)
+        }
+    }
+
+    fn cell(&self, coord: &Coord) -> (char, Option<Colour>) {
+        let mut out = match self.base.get(coord) {
+            Some(c) => (*c, self.base_colour),
+            None => (self.background, None),
+        };
+        for layer in &self.layers {
+            if let Some(glyph) = layer.cells.get(coord) {
+                out = (glyph.unwrap_or(out.0), layer.colour.or(out.1));
+            }
+        }
+        out
+    }
+
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    /// Every cell of the drawn area, as it would be drawn (ignoring `colour(false)`)
+    pub fn pixels(&self) -> DenseGrid<Pixel> {
+        let ([xmin, ymin], [xmax, ymax]) = self.extent();
+        let mut out = DenseGrid::with_origin(
+            [xmin, ymin],
+            (xmax - xmin + 1) as usize,
+            (ymax - ymin + 1) as usize,
+        );
+        for y in ymin..=ymax {
+            for x in xmin..=xmax {
+                let (glyph, colour) = self.cell(&[x, y]);
+                out.insert([x, y], Pixel { glyph, colour });
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Renderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ([xmin, ymin], [xmax, ymax]) = self.extent();
+
+        let ylabel_width = if self.axes {
+            [ymin, ymax]
+                .map(|y| y.to_string().len())
+                .into_iter()
+                .max()
+                .unwrap_or(1)
+        } else {
+            0
+        };
+
+        if self.axes {
+            // column numbers, written top to bottom
+            let labels: Vec<Vec<char>> = (xmin..=xmax)
+                .map(|x| x.to_string().chars().collect())
+                .collect();
+            let rows = labels.iter().map(Vec::len).max().unwrap_or(0);
+            for row in 0..rows {
+                write!(f, "{:w$} ", "", w = ylabel_width)?;
+                for l in &labels {
+                    let pad = rows - l.len();
+                    let c = if row < pad { ' ' } else { l[row - pad] };
+                    write!(f, "{c}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for y in ymin..=ymax {
+            if self.axes {
+                write!(f, "{y:>w$} ", w = ylabel_width)?;
+            }
+            let mut run: Option<Colour> = None;
+            for x in xmin..=xmax {
+                let (glyph, colour) = self.cell(&[x, y]);
+                let colour = colour.filter(|_| self.colour);
+                if colour != run {
+                    if run.is_some() {
+                        write!(f, "\x1b[0m")?;
+                    }
+                    if let Some(c) = colour {
+                        write!(f, "\x1b[{}m", c.ansi_code())?;
+                    }
+                    run = colour;
+                }
+                write!(f, "{glyph}")?;
+            }
+            if run.is_some() {
+                write!(f, "\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+    use std::collections::HashSet;
+
+    fn walls() -> HashMap<Coord, char> {
+        <HashMap<Coord, char> as Grid>::from_str_with("###\n#..\n###", |c| (c == '#').then_some(c))
+    }
+
+    #[test]
+    fn layers() {
+        let path: HashSet<Coord> = [[1, 1], [2, 1]].into();
+        let r = Renderer::new(&walls())
+            .background(' ')
+            .layer(Layer::new(path).glyph('o'))
+            .layer(Layer::glyphs([([2, 1], '>')]))
+            .plain();
+        assert_eq!(r.render(), "###\n#o>\n###\n");
+
+        // layers can extend the drawn area
+        let r = Renderer::new(&walls()).layer(Layer::new([[4, 0]]).glyph('!'));
+        assert_eq!(r.plain().render(), "###.!\n#....\n###..\n");
+    }
+
+    #[test]
+    fn colour() {
+        let r = Renderer::new(&walls())
+            .layer(Layer::new([[1, 1], [2, 1]]).colour(Colour::Red))
+            .layer(Layer::new([[0, 1]]).glyph('@'))
+            .colour(true);
+        assert_eq!(r.render(), "###\n@\x1b[31m..\x1b[0m\n###\n");
+    }
+
+    #[test]
+    fn axes() {
+        let r = Renderer::new(&walls())
+            .layer(Layer::new([[10, -1]]).glyph('x'))
+            .axes(true)
+            .plain();
+        assert_eq!(
+            r.render(),
+            "             1\n   01234567890\n-1 ..........x\n 0 ###........\n 1 #..........\n 2 ###........\n"
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Shortest paths over an arbitrary state type.
+//!
+//! States are usually a `Coord`, but can be anything hashable,
+//! e.g. `(Coord, Dir4)` when facing matters.
+//! Neighbours are supplied by a closure, so walls, bounds and so on
+//! are up to the caller.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Distances and predecessors from a search
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    /// Cost of the cheapest known route from the start to each state
+    pub dist: HashMap<S, usize>,
+    /// The state each state was reached from, on that cheapest route
+    pub prev: HashMap<S, S>,
+    /// The goal reached, for searches that have one (e.g. `astar`)
+    pub goal: Option<S>,
+}
+
+impl<S> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+{
+    /// The route from the start to `to`, inclusive of both ends
+    pub fn path_to(&self, to: &S) -> Option<Vec<S>> {
+        if !self.dist.contains_key(to) {
+            return None;
+        }
+        let mut out = vec![to.clone()];
+        let mut here = to;
+        while let Some(p) = self.prev.get(here) {
+            out.push(p.clone());
+            here = p;
+        }
+        out.reverse();
+        Some(out)
+    }
+
+    /// The route from the start to the goal, if one was reached
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|g| self.path_to(g))
+    }
+
+    /// The cost of reaching the goal, if one was reached
+    pub fn cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|g| self.dist.get(g)).copied()
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+///
+/// Explores everything reachable from `start`.
+pub fn bfs<S, N, I>(start: S, mut neighbours: N) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    dist.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(here) = queue.pop_front() {
+        let d = dist[&here];
+        for next in neighbours(&here) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                prev.insert(next.clone(), here.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    SearchResult {
+        dist,
+        prev,
+        goal: None,
+    }
+}
+
+/// Dijkstra's algorithm: `neighbours` yields `(state, step cost)`.
+///
+/// Explores everything reachable from `start`.
+pub fn dijkstra<S, N, I>(start: S, neighbours: N) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(start, neighbours, |_| 0, |_| false)
+}
+
+/// A* search: stops at the first state for which `is_goal` holds.
+///
+/// `heuristic` must never overestimate the remaining cost
+/// (e.g. Manhattan distance on a 4-connected grid).
+pub fn astar<S, N, I, H, G>(start: S, neighbours: N, heuristic: H, is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, neighbours, heuristic, is_goal)
+}
+
+fn best_first<S, N, I, H, G>(
+    start: S,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), 0);
+    heap.push(Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    });
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if cost > dist[&state] {
+            // stale entry; we've since found a cheaper way here
+            continue;
+        }
+        if is_goal(&state) {
+            return SearchResult {
+                dist,
+                prev,
+                goal: Some(state),
+            };
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if next_cost < *dist.get(&next).unwrap_or(&usize::MAX) {
+                dist.insert(next.clone(), next_cost);
+                prev.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    SearchResult {
+        dist,
+        prev,
+        goal: None,
+    }
+}
+
+/// Every optimal route from a `dijkstra_all` search.
+///
+/// Routes run backwards from any state to the start via `preds`,
+/// so they form a DAG as long as every step costs at least 1.
+#[derive(Debug, Clone)]
+pub struct AllPaths<S> {
+    /// Cost of the cheapest route from the start to each state
+    pub dist: HashMap<S, usize>,
+    /// Every state from which each state is reached at its cheapest cost
+    pub preds: HashMap<S, Vec<S>>,
+}
+
+impl<S> AllPaths<S>
+where
+    S: Clone + Eq + Hash,
+{
+    /// Those of `goals` which are reachable at the lowest cost among them
+    pub fn best_goals<I: IntoIterator<Item = S>>(&self, goals: I) -> Vec<S> {
+        let reached: Vec<(S, usize)> = goals
+            .into_iter()
+            .filter_map(|g| self.dist.get(&g).map(|d| (g, *d)))
+            .collect();
+        let best = reached.iter().map(|(_, d)| *d).min();
+        reached
+            .into_iter()
+            .filter(|(_, d)| Some(*d) == best)
+            .map(|(g, _)| g)
+            .collect()
+    }
+
+    /// Every state lying on any optimal route to the best of `goals`
+    pub fn on_optimal_paths<I: IntoIterator<Item = S>>(&self, goals: I) -> HashSet<S> {
+        let mut out = HashSet::new();
+        let mut queue = self.best_goals(goals);
+
+        while let Some(s) = queue.pop() {
+            // a state can be queued again before it's first expanded
+            if !out.insert(s.clone()) {
+                continue;
+            }
+            if let Some(ps) = self.preds.get(&s) {
+                queue.extend(ps.iter().filter(|p| !out.contains(*p)).cloned());
+            }
+        }
+        out
+    }
+
+    /// The number of distinct optimal routes to the best of `goals` (saturating)
+    pub fn count_paths<I: IntoIterator<Item = S>>(&self, goals: I) -> usize {
+        let goals = self.best_goals(goals);
+        let mut states: Vec<S> = self.on_optimal_paths(goals.clone()).into_iter().collect();
+        states.sort_by_key(|s| self.dist[s]);
+
+        let mut counts: HashMap<S, usize> = HashMap::new();
+        for s in states {
+            let n = match self.preds.get(&s) {
+                Some(ps) => ps
+                    .iter()
+                    .fold(0_usize, |acc, p| acc.saturating_add(counts[p])),
+                None => 1,
+            };
+            counts.insert(s, n);
+        }
+
+        goals.iter().fold(0, |acc, g| acc.saturating_add(counts[g]))
+    }
+
+    /// Lazily enumerate every optimal route (start to goal, inclusive)
+    /// to the best of `goals`
+    pub fn paths<I: IntoIterator<Item = S>>(&self, goals: I) -> Paths<'_, S> {
+        Paths {
+            preds: &self.preds,
+            goals: self.best_goals(goals),
+            stack: vec![],
+        }
+    }
+}
+
+/// Iterator over optimal routes; see `AllPaths::paths`
+pub struct Paths<'a, S> {
+    preds: &'a HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+    /// The route so far, backwards from the goal,
+    /// with the index of the next predecessor to try at each step
+    stack: Vec<(S, usize)>,
+}
+
+impl<S> Iterator for Paths<'_, S>
+where
+    S: Clone + Eq + Hash,
+{
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.stack.is_empty() {
+                let g = self.goals.pop()?;
+                self.stack.push((g, 0));
+            }
+
+            let (state, i) = self.stack.last_mut()?;
+            let preds = self.preds.get(state).map(Vec::as_slice).unwrap_or(&[]);
+
+            if preds.is_empty() {
+                // back at the start
+                let path = self.stack.iter().rev().map(|(s, _)| s.clone()).collect();
+                self.stack.pop();
+                return Some(path);
+            } else if *i < preds.len() {
+                let p = preds[*i].clone();
+                *i += 1;
+                self.stack.push((p, 0));
+            } else {
+                self.stack.pop();
+            }
+        }
+    }
+}
+
+/// Dijkstra's algorithm, recording every tied-optimal predecessor
+/// rather than just the first found.
+///
+/// Step costs should be at least 1.
+pub fn dijkstra_all<S, N, I>(start: S, mut neighbours: N) -> AllPaths<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut dist = HashMap::new();
+    let mut preds: HashMap<S, Vec<S>> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start.clone(), 0);
+    heap.push(Entry {
+        priority: 0,
+        cost: 0,
+        state: start,
+    });
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if cost > dist[&state] {
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            let old_cost = *dist.get(&next).unwrap_or(&usize::MAX);
+            if next_cost < old_cost {
+                dist.insert(next.clone(), next_cost);
+                preds.insert(next.clone(), vec![state.clone()]);
+                heap.push(Entry {
+                    priority: next_cost,
+                    cost: next_cost,
+                    state: next,
+                });
+            } else if next_cost == old_cost {
+                let ps = preds.entry(next).or_default();
+                if !ps.contains(&state) {
+                    ps.push(state.clone());
+                }
+            }
+        }
+    }
+
+    AllPaths { dist, preds }
+}
+
+/// Heap entry, ordered so that the lowest priority pops first
+pub(crate) struct Entry<S> {
+    pub(crate) priority: usize,
+    pub(crate) cost: usize,
+    pub(crate) state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{add, Coord, Dir4, Grid};
+
+    const MAZE: &str = r"#######
+#S....#
+#.###.#
+#...#.#
+###.#E#
+#######";
+
+    fn open<'a>(walls: &'a HashSet<Coord>, c: &Coord) -> impl Iterator<Item = Coord> + 'a {
+        let c = *c;
+        Dir4::ALL
+            .into_iter()
+            .map(move |d| add(c, d.to_coord()))
+            .filter(move |n| !walls.contains(n))
+    }
+
+    #[test]
+    fn bfs_maze() {
+        let walls = <HashSet<Coord> as Grid>::from_str_with(MAZE, |c| (c == '#').then_some(c));
+        let res = bfs([1, 1], |c| open(&walls, c));
+
+        assert_eq!(res.dist[&[5, 4]], 7);
+        assert_eq!(res.dist[&[3, 4]], 5);
+        let path = res.path_to(&[5, 4]).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), Some(&[1, 1]));
+        assert_eq!(path.last(), Some(&[5, 4]));
+        assert!(res.path_to(&[0, 0]).is_none());
+    }
+
+    #[test]
+    fn weighted() {
+        // going down is expensive
+        let walls = <HashSet<Coord> as Grid>::from_str_with(MAZE, |c| (c == '#').then_some(c));
+        let cost = |from: &Coord, to: &Coord| if to[1] > from[1] { 10 } else { 1 };
+        let neighbours = |c: &Coord| {
+            let from = *c;
+            open(&walls, c).map(move |n| (n, cost(&from, &n)))
+        };
+
+        let res = dijkstra([1, 1], neighbours);
+        assert_eq!(res.dist[&[5, 4]], 4 + 30);
+        assert_eq!(res.dist[&[3, 4]], 2 + 30);
+
+        let res = astar(
+            [1, 1],
+            neighbours,
+            |c| c[0].abs_diff(5) + c[1].abs_diff(4),
+            |c| *c == [5, 4],
+        );
+        assert_eq!(res.cost(), Some(34));
+        assert_eq!(res.path().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn all_paths() {
+        // three equally short ways down to [5, 3]
+        const ROOMS: &str = r"#######
+#S....#
+#.#.#.#
+#.....#
+#.###.#
+#.....#
+#######";
+        let walls = <HashSet<Coord> as Grid>::from_str_with(ROOMS, |c| (c == '#').then_some(c));
+        let res = dijkstra_all([1, 1], |c| open(&walls, c).map(|n| (n, 1)));
+
+        assert_eq!(res.dist[&[5, 3]], 6);
+        assert_eq!(res.count_paths([[5, 3]]), 3);
+        assert_eq!(res.paths([[5, 3]]).count(), 3);
+        assert!(res.paths([[5, 3]]).all(|p| p.len() == 7 && p[0] == [1, 1]));
+        assert_eq!(res.on_optimal_paths([[5, 3]]).len(), 13);
+
+        // only the nearest of several goals counts
+        assert_eq!(res.best_goals([[5, 5], [5, 3]]), [[5, 3]]);
+        assert_eq!(res.count_paths([[0, 0]]), 0);
+    }
+
+    #[test]
+    fn lattice() {
+        // every cell is on some shortest path, along very many routes
+        let res = dijkstra_all([0, 0], |&[x, y]: &Coord| {
+            [[x + 1, y], [x, y + 1]]
+                .into_iter()
+                .filter(|[x, y]| *x < 60 && *y < 60)
+                .map(|n| (n, 1))
+        });
+        assert_eq!(res.on_optimal_paths([[59, 59]]).len(), 60 * 60);
+        assert_eq!(res.count_paths([[59, 59]]), usize::MAX);
+    }
+}
@@ -0,0 +1,104 @@
+//! Lazily walking the coordinates of common shapes, without building a grid first.
+
+use std::ops::RangeInclusive;
+
+use crate::{add, Coord};
+
+/// Every coordinate within Manhattan distance `r` of `centre`, in reading order
+pub fn manhattan_ball(centre: Coord, r: isize) -> impl Iterator<Item = Coord> {
+    let [cx, cy] = centre;
+    (-r..=r).flat_map(move |dy| {
+        let w = r - dy.abs();
+        (-w..=w).map(move |dx| [cx + dx, cy + dy])
+    })
+}
+
+/// Every coordinate within Chebyshev distance `r` of `centre` (a square), in reading order
+pub fn chebyshev_ball(centre: Coord, r: isize) -> impl Iterator<Item = Coord> {
+    let [cx, cy] = centre;
+    (-r..=r).flat_map(move |dy| (-r..=r).map(move |dx| [cx + dx, cy + dy]))
+}
+
+/// Every coordinate at exactly Manhattan distance `r` from `centre`, in reading order.
+///
+/// A ring of radius 0 is just the centre.
+pub fn manhattan_ring(centre: Coord, r: isize) -> impl Iterator<Item = Coord> {
+    let [cx, cy] = centre;
+    (-r..=r).flat_map(move |dy| {
+        let w = r - dy.abs();
+        let sides = if w == 0 { 1 } else { 2 };
+        [-w, w]
+            .into_iter()
+            .take(sides)
+            .map(move |dx| [cx + dx, cy + dy])
+    })
+}
+
+/// `start`, `start + step`, `start + 2 * step`, ... for as long as it stays within `bounds`.
+///
+/// A zero `step` yields `start` once, rather than forever.
+pub fn ray(
+    start: Coord,
+    step: Coord,
+    bounds: [RangeInclusive<isize>; 2],
+) -> impl Iterator<Item = Coord> {
+    let [xs, ys] = bounds;
+    let limit = if step == [0, 0] { 1 } else { usize::MAX };
+    std::iter::successors(Some(start), move |&p| Some(add(p, step)))
+        .take_while(move |[x, y]| xs.contains(x) && ys.contains(y))
+        .take(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec2;
+
+    #[test]
+    fn balls() {
+        let diamond: Vec<Coord> = manhattan_ball([5, 5], 1).collect();
+        assert_eq!(diamond, [[5, 4], [4, 5], [5, 5], [6, 5], [5, 6]]);
+        assert_eq!(manhattan_ball([0, 0], 20).count(), 2 * 20 * 21 + 1);
+        let centre = Vec2::from([3, -2]);
+        let ball: Vec<Coord> = manhattan_ball([3, -2], 7).collect();
+        assert_eq!(ball.len(), 2 * 7 * 7 + 2 * 7 + 1);
+        assert!(ball
+            .iter()
+            .all(|&c| (Vec2::from(c) - centre).manhattan() <= 7));
+
+        assert_eq!(chebyshev_ball([0, 0], 2).count(), 25);
+        assert_eq!(manhattan_ball([0, 0], 0).collect::<Vec<_>>(), [[0, 0]]);
+        assert_eq!(manhattan_ball([0, 0], -1).count(), 0);
+    }
+
+    #[test]
+    fn rings() {
+        let ring: Vec<Coord> = manhattan_ring([0, 0], 2).collect();
+        assert_eq!(
+            ring,
+            [
+                [0, -2],
+                [-1, -1],
+                [1, -1],
+                [-2, 0],
+                [2, 0],
+                [-1, 1],
+                [1, 1],
+                [0, 2]
+            ]
+        );
+        assert_eq!(manhattan_ring([1, 1], 0).collect::<Vec<_>>(), [[1, 1]]);
+        for r in 1..10 {
+            assert_eq!(manhattan_ring([0, 0], r).count() as isize, 4 * r);
+        }
+    }
+
+    #[test]
+    fn rays() {
+        let r: Vec<Coord> = ray([1, 0], [2, 1], [0..=9, 0..=9]).collect();
+        assert_eq!(r, [[1, 0], [3, 1], [5, 2], [7, 3], [9, 4]]);
+        assert_eq!(ray([-1, 0], [1, 0], [0..=9, 0..=9]).count(), 0);
+        assert_eq!(ray([4, 4], [0, 0], [0..=9, 0..=9]).count(), 1);
+        assert_eq!(ray([4, 4], [0, -1], [0..=9, 0..=9]).last(), Some([4, 0]));
+    }
+}
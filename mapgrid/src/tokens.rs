@@ -0,0 +1,202 @@
+//! Grids where a cell is written as a string of several characters,
+//! such as a box drawn `[]` that takes up two columns.
+//!
+//! A token table lists each token with the value it stands for (`None` for empty space).
+//! A wide token's value goes in its leftmost column; the columns after it are left empty.
+
+use std::fmt;
+
+use crate::{Cells, Coord, FromCells, ParseError};
+
+/// No token in the table matches the text from this point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoToken;
+
+impl fmt::Display for NoToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no token matches")
+    }
+}
+
+impl std::error::Error for NoToken {}
+
+/// Replace each character found in `table` with its string; others are kept as they are
+pub fn expand(input: &str, table: &[(char, &str)]) -> String {
+    let mut out = String::with_capacity(input.len() * 2);
+    for c in input.chars() {
+        match table.iter().find(|(k, _)| *k == c) {
+            Some((_, s)) => out.push_str(s),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// Parse using a token table, preferring the longest match.
+///
+/// Lines must all be the same length, as for `TryGrid::try_from_str_with`.
+pub fn parse_tokens<G, V>(
+    input: &str,
+    tokens: &[(&str, Option<V>)],
+) -> Result<G, ParseError<NoToken>>
+where
+    G: FromCells<V>,
+    V: Clone,
+{
+    let mut by_length: Vec<(Vec<char>, &Option<V>)> = tokens
+        .iter()
+        .map(|(t, v)| (t.chars().collect(), v))
+        .collect();
+    by_length.sort_by_key(|(t, _)| std::cmp::Reverse(t.len()));
+
+    let mut cells = vec![];
+    let mut width = None;
+    let mut height = 0;
+
+    for (row, s) in input.lines().enumerate() {
+        let line: Vec<char> = s.chars().collect();
+        let expected = *width.get_or_insert(line.len());
+        if line.len() != expected {
+            return Err(ParseError::Ragged {
+                line: row + 1,
+                expected,
+                found: line.len(),
+            });
+        }
+
+        let mut col = 0;
+        while col < line.len() {
+            let Some((t, v)) = by_length
+                .iter()
+                .find(|(t, _)| !t.is_empty() && line[col..].starts_with(t))
+            else {
+                return Err(ParseError::Cell {
+                    line: row + 1,
+                    column: col + 1,
+                    found: line[col],
+                    source: NoToken,
+                });
+            };
+            if let Some(v) = v {
+                cells.push(([col as isize, row as isize], v.clone()));
+            }
+            col += t.len();
+        }
+        height = row + 1;
+    }
+
+    let width = width.unwrap_or(0) as isize;
+    Ok(G::from_cells(
+        [0..=width - 1, 0..=height as isize - 1],
+        cells,
+    ))
+}
+
+/// The inverse of `parse_tokens`.
+///
+/// Empty columns are drawn with the `None` token (or `.` if there isn't one),
+/// except where they're covered by a wide token to their left.
+/// Values not in the table are drawn as `?`.
+pub fn render_tokens<G, V>(grid: &G, tokens: &[(&str, Option<V>)]) -> String
+where
+    G: Cells<V>,
+    V: PartialEq,
+{
+    let token = |v: &V| {
+        tokens
+            .iter()
+            .find(|(_, t)| t.as_ref() == Some(v))
+            .map_or("?", |(t, _)| *t)
+    };
+    let empty = tokens
+        .iter()
+        .find(|(_, t)| t.is_none())
+        .map_or(".", |(t, _)| *t);
+
+    let cells: Vec<(Coord, &str)> = grid.cells().map(|(k, v)| (k, token(v))).collect();
+    if cells.is_empty() {
+        return String::new();
+    }
+    let xmin = cells.iter().map(|([x, _], _)| *x).min().unwrap_or(0);
+    let ymin = cells.iter().map(|([_, y], _)| *y).min().unwrap_or(0);
+    let ymax = cells.iter().map(|([_, y], _)| *y).max().unwrap_or(0);
+    let xend = cells
+        .iter()
+        .map(|([x, _], t)| x + t.chars().count() as isize)
+        .max()
+        .unwrap_or(0);
+
+    let mut out = String::new();
+    for y in ymin..=ymax {
+        let mut x = xmin;
+        while x < xend {
+            match grid.cell(&[x, y]) {
+                Some(v) => {
+                    let t = token(v);
+                    out.push_str(t);
+                    x += t.chars().count().max(1) as isize;
+                }
+                None => {
+                    out.push_str(empty);
+                    x += empty.chars().count().max(1) as isize;
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Box,
+        Robot,
+    }
+
+    const NARROW: &str = r"#####
+#.O@#
+#####";
+
+    const WIDE: [(char, &str); 4] = [('#', "##"), ('O', "[]"), ('.', ".."), ('@', "@.")];
+
+    const TILES: [(&str, Option<Tile>); 4] = [
+        ("#", Some(Tile::Wall)),
+        ("[]", Some(Tile::Box)),
+        ("@", Some(Tile::Robot)),
+        (".", None),
+    ];
+
+    #[test]
+    fn wide_boxes() {
+        let wide = expand(NARROW, &WIDE);
+        assert_eq!(wide, "##########\n##..[]@.##\n##########");
+
+        let grid: HashMap<Coord, Tile> = parse_tokens(&wide, &TILES).unwrap();
+        assert_eq!(grid[&[4, 1]], Tile::Box);
+        assert!(!grid.contains_key(&[5, 1]));
+        assert_eq!(grid[&[6, 1]], Tile::Robot);
+        assert_eq!(grid.len(), 10 + 10 + 4 + 2);
+
+        assert_eq!(render_tokens(&grid, &TILES), wide.clone() + "\n");
+    }
+
+    #[test]
+    fn bad_tokens() {
+        let e = parse_tokens::<HashMap<Coord, Tile>, _>("#[]\n#[.", &TILES).unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::Cell {
+                line: 2,
+                column: 2,
+                found: '[',
+                source: NoToken
+            }
+        );
+    }
+}
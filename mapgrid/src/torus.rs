@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::{Cells, Coord, Dir4, Dir8, Renderer};
+
+/// A fixed-size space whose edges wrap around: stepping off the east edge
+/// brings you back on the west edge, and likewise north and south.
+///
+/// Coordinates within it run from [0, 0] to [width - 1, height - 1].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus {
+    width: isize,
+    height: isize,
+}
+
+impl Torus {
+    /// Panics unless both dimensions are positive
+    pub fn new(width: isize, height: isize) -> Self {
+        assert!(width > 0 && height > 0, "Torus must be at least 1x1");
+        Self { width, height }
+    }
+
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    /// Is `coord` already wrapped?
+    pub fn contains(&self, coord: &Coord) -> bool {
+        (0..self.width).contains(&coord[0]) && (0..self.height).contains(&coord[1])
+    }
+
+    /// The equivalent coordinate within the torus
+    pub fn wrap(&self, [x, y]: Coord) -> Coord {
+        [x.rem_euclid(self.width), y.rem_euclid(self.height)]
+    }
+
+    /// Elementwise add, then wrap
+    pub fn add(&self, a: Coord, b: Coord) -> Coord {
+        self.wrap([a[0] + b[0], a[1] + b[1]])
+    }
+
+    /// Where something at `pos` moving at `velocity` per step is after `steps` steps.
+    ///
+    /// Any of these may be negative, and large step counts don't overflow.
+    pub fn advance(&self, pos: Coord, velocity: Coord, steps: isize) -> Coord {
+        let dims = [self.width, self.height];
+        let [x, y] = [0, 1].map(|i| {
+            let d = dims[i];
+            pos[i].rem_euclid(d) + velocity[i].rem_euclid(d) * steps.rem_euclid(d)
+        });
+        self.wrap([x, y])
+    }
+
+    /// `coord + offset` for each offset, wrapped
+    pub fn neighbours_with<I>(&self, coord: Coord, offsets: I) -> impl Iterator<Item = Coord>
+    where
+        I: IntoIterator<Item = Coord>,
+    {
+        let torus = *self;
+        offsets.into_iter().map(move |d| torus.add(coord, d))
+    }
+
+    /// Orthogonally adjacent, clockwise from north
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        self.neighbours_with(coord, Dir4::ALL.map(Dir4::to_coord))
+    }
+
+    /// Orthogonally or diagonally adjacent, clockwise from north
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        self.neighbours_with(coord, Dir8::ALL.map(Dir8::to_coord))
+    }
+
+    /// Every coordinate, in reading order
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let (w, h) = (self.width, self.height);
+        (0..h).flat_map(move |y| (0..w).map(move |x| [x, y]))
+    }
+
+    /// Draws the whole torus, with every cell of `grid` wrapped into it
+    pub fn renderer<G, V, F>(&self, grid: &G, glyph: F) -> Renderer
+    where
+        G: Cells<V>,
+        F: Fn(&V) -> char,
+    {
+        let wrapped: HashMap<Coord, char> = grid
+            .cells()
+            .map(|(k, v)| (self.wrap(k), glyph(v)))
+            .collect();
+        Renderer::new(&wrapped).area(0..=self.width - 1, 0..=self.height - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn wrapping() {
+        let t = Torus::new(11, 7);
+        assert_eq!(t.wrap([-1, 7]), [10, 0]);
+        assert_eq!(t.add([10, 6], [1, 1]), [0, 0]);
+        assert!(t.contains(&[10, 6]));
+        assert!(!t.contains(&[11, 0]));
+
+        // the robot from the day 14 example
+        let (p, v) = ([2, 4], [2, -3]);
+        let steps: Vec<Coord> = (0..=5).map(|s| t.advance(p, v, s)).collect();
+        assert_eq!(steps, [[2, 4], [4, 1], [6, 5], [8, 2], [10, 6], [1, 3]]);
+        assert_eq!(t.advance(p, v, 5 + 11 * 7 * 1_000_000_000), [1, 3]);
+        assert_eq!(t.advance(p, v, -1), [0, 0]);
+
+        let n: Vec<Coord> = t.neighbours4([0, 0]).collect();
+        assert_eq!(n, [[0, 6], [1, 0], [0, 1], [10, 0]]);
+        assert_eq!(t.neighbours8([5, 5]).count(), 8);
+        assert_eq!(t.coords().count(), 77);
+    }
+
+    #[test]
+    fn rendering() {
+        let t = Torus::new(4, 2);
+        let robots: HashSet<Coord> = [[-1, 0], [5, 3]].into();
+        assert_eq!(
+            t.renderer(&robots, |_| '#').plain().render(),
+            "...#\n.#..\n"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use crate::{BitGrid, Cells, Coord, DenseGrid, Grid};
+
+/// Building a grid out of cells: the inverse of `Cells::cells`
+pub trait FromCells<V, const N: usize = 2>: Sized {
+    /// `bounds` is the intended extent of the new grid, which sparse grids may ignore
+    fn from_cells<I>(bounds: [RangeInclusive<isize>; N], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord<N>, V)>;
+}
+
+impl<const N: usize, V> FromCells<V, N> for HashMap<Coord<N>, V> {
+    fn from_cells<I>(_: [RangeInclusive<isize>; N], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord<N>, V)>,
+    {
+        cells.into_iter().collect()
+    }
+}
+
+impl<const N: usize, V> FromCells<V, N> for BTreeMap<Coord<N>, V> {
+    fn from_cells<I>(_: [RangeInclusive<isize>; N], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord<N>, V)>,
+    {
+        cells.into_iter().collect()
+    }
+}
+
+impl<const N: usize> FromCells<(), N> for HashSet<Coord<N>> {
+    fn from_cells<I>(_: [RangeInclusive<isize>; N], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord<N>, ())>,
+    {
+        cells.into_iter().map(|(k, _)| k).collect()
+    }
+}
+
+impl<const N: usize> FromCells<(), N> for BTreeSet<Coord<N>> {
+    fn from_cells<I>(_: [RangeInclusive<isize>; N], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord<N>, ())>,
+    {
+        cells.into_iter().map(|(k, _)| k).collect()
+    }
+}
+
+/// Sized to `bounds`, growing if any cells fall outside them
+impl<V> FromCells<V> for DenseGrid<V> {
+    fn from_cells<I>([xs, ys]: [RangeInclusive<isize>; 2], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord, V)>,
+    {
+        let cells: Vec<(Coord, V)> = cells.into_iter().collect();
+
+        let xmin = cells
+            .iter()
+            .map(|(k, _)| k[0])
+            .fold(*xs.start(), isize::min);
+        let xmax = cells.iter().map(|(k, _)| k[0]).fold(*xs.end(), isize::max);
+        let ymin = cells
+            .iter()
+            .map(|(k, _)| k[1])
+            .fold(*ys.start(), isize::min);
+        let ymax = cells.iter().map(|(k, _)| k[1]).fold(*ys.end(), isize::max);
+
+        let mut out = DenseGrid::with_origin(
+            [xmin, ymin],
+            (xmax - xmin + 1).max(0) as usize,
+            (ymax - ymin + 1).max(0) as usize,
+        );
+        for (k, v) in cells {
+            out.insert(k, v);
+        }
+        out
+    }
+}
+
+/// Sized to `bounds`, growing if any cells fall outside them
+impl FromCells<()> for BitGrid {
+    fn from_cells<I>([xs, ys]: [RangeInclusive<isize>; 2], cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord, ())>,
+    {
+        let cells: Vec<Coord> = cells.into_iter().map(|(k, _)| k).collect();
+
+        let xmin = cells.iter().map(|k| k[0]).fold(*xs.start(), isize::min);
+        let xmax = cells.iter().map(|k| k[0]).fold(*xs.end(), isize::max);
+        let ymin = cells.iter().map(|k| k[1]).fold(*ys.start(), isize::min);
+        let ymax = cells.iter().map(|k| k[1]).fold(*ys.end(), isize::max);
+
+        let mut out = BitGrid::with_origin(
+            [xmin, ymin],
+            (xmax - xmin + 1).max(0) as usize,
+            (ymax - ymin + 1).max(0) as usize,
+        );
+        out.extend(cells);
+        out
+    }
+}
+
+/// The eight ways to rotate and/or reflect a rectangle onto itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// 90° clockwise
+    RotateCw,
+    Rotate180,
+    /// 90° anticlockwise
+    RotateCcw,
+    /// Mirror left-to-right
+    FlipHorizontal,
+    /// Mirror top-to-bottom
+    FlipVertical,
+    /// Mirror along the main (top-left to bottom-right) diagonal
+    Transpose,
+    /// Mirror along the other diagonal
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateCw,
+        Symmetry::Rotate180,
+        Symmetry::RotateCcw,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// Does this turn a `w`×`h` rectangle into an `h`×`w` one?
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Symmetry::RotateCw
+                | Symmetry::RotateCcw
+                | Symmetry::Transpose
+                | Symmetry::AntiTranspose
+        )
+    }
+
+    /// Where `[x, y]` ends up, for a `width`×`height` rectangle with its top-left at [0, 0]
+    pub fn apply(self, [x, y]: Coord, width: isize, height: isize) -> Coord {
+        let (w, h) = (width - 1, height - 1);
+        match self {
+            Symmetry::Identity => [x, y],
+            Symmetry::RotateCw => [h - y, x],
+            Symmetry::Rotate180 => [w - x, h - y],
+            Symmetry::RotateCcw => [y, w - x],
+            Symmetry::FlipHorizontal => [w - x, y],
+            Symmetry::FlipVertical => [x, h - y],
+            Symmetry::Transpose => [y, x],
+            Symmetry::AntiTranspose => [h - y, w - x],
+        }
+    }
+}
+
+/// Rearranging a whole grid, giving a new grid of the same type.
+///
+/// Rotations and reflections are about the grid's `bounds`,
+/// and keep its top-left corner where it was.
+pub trait Transform<V>: Grid<V> + Cells<V> + FromCells<V>
+where
+    V: Clone,
+{
+    fn apply(&self, symmetry: Symmetry) -> Self {
+        let [xs, ys] = self.bounds();
+        let (x0, y0) = (*xs.start(), *ys.start());
+        let (w, h) = (xs.end() - x0 + 1, ys.end() - y0 + 1);
+        let (nw, nh) = if symmetry.swaps_axes() {
+            (h, w)
+        } else {
+            (w, h)
+        };
+
+        Self::from_cells(
+            [x0..=x0 + nw - 1, y0..=y0 + nh - 1],
+            self.cells().map(|([x, y], v)| {
+                let [nx, ny] = symmetry.apply([x - x0, y - y0], w, h);
+                ([nx + x0, ny + y0], v.clone())
+            }),
+        )
+    }
+
+    /// 90° clockwise
+    fn rotate_cw(&self) -> Self {
+        self.apply(Symmetry::RotateCw)
+    }
+
+    /// 90° anticlockwise
+    fn rotate_ccw(&self) -> Self {
+        self.apply(Symmetry::RotateCcw)
+    }
+
+    fn rotate_180(&self) -> Self {
+        self.apply(Symmetry::Rotate180)
+    }
+
+    /// Mirror left-to-right
+    fn flip_horizontal(&self) -> Self {
+        self.apply(Symmetry::FlipHorizontal)
+    }
+
+    /// Mirror top-to-bottom
+    fn flip_vertical(&self) -> Self {
+        self.apply(Symmetry::FlipVertical)
+    }
+
+    /// Swap rows and columns
+    fn transpose(&self) -> Self {
+        self.apply(Symmetry::Transpose)
+    }
+
+    /// Only the cells within `xs` and `ys`
+    fn crop(&self, xs: RangeInclusive<isize>, ys: RangeInclusive<isize>) -> Self {
+        let cells = self
+            .cells()
+            .filter(|([x, y], _)| xs.contains(x) && ys.contains(y))
+            .map(|(k, v)| (k, v.clone()));
+        Self::from_cells([xs.clone(), ys.clone()], cells)
+    }
+
+    /// Grow the bounds by `n` on every side, filling the new border with `fill`
+    fn pad(&self, n: usize, fill: V) -> Self {
+        let [xs, ys] = self.bounds();
+        let n = n as isize;
+        let (nxs, nys) = (xs.start() - n..=xs.end() + n, ys.start() - n..=ys.end() + n);
+
+        let mut border = vec![];
+        for y in nys.clone() {
+            for x in nxs.clone() {
+                if !(xs.contains(&x) && ys.contains(&y)) {
+                    border.push(([x, y], fill.clone()));
+                }
+            }
+        }
+
+        Self::from_cells(
+            [nxs, nys],
+            self.cells().map(|(k, v)| (k, v.clone())).chain(border),
+        )
+    }
+
+    /// All eight rotations and reflections, starting with an unchanged copy
+    fn symmetries(&self) -> impl Iterator<Item = Self> {
+        Symmetry::ALL.into_iter().map(|s| self.apply(s))
+    }
+}
+
+impl<G, V> Transform<V> for G
+where
+    G: Grid<V> + Cells<V> + FromCells<V>,
+    V: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const L: &str = r"#..
+#..
+##.";
+
+    fn parse(s: &str) -> BTreeMap<Coord, char> {
+        <BTreeMap<Coord, char> as Grid>::from_str_with(s, |c| (c == '#').then_some(c))
+    }
+
+    #[test]
+    fn rotations() {
+        let g = parse(L);
+        assert_eq!(g.rotate_cw().visualise(), "###\n#..\n");
+        assert_eq!(g.rotate_ccw().visualise(), "..#\n###\n");
+        assert_eq!(g.rotate_180().visualise(), "##\n.#\n.#\n");
+        assert_eq!(g.rotate_cw().rotate_cw(), g.rotate_180());
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+
+    #[test]
+    fn reflections() {
+        let g = parse(L);
+        assert_eq!(g.flip_horizontal().visualise(), ".#\n.#\n##\n");
+        assert_eq!(g.flip_vertical().visualise(), "##\n#.\n#.\n");
+        assert_eq!(g.transpose().visualise(), "###\n..#\n");
+        assert_eq!(g.transpose().transpose(), g);
+
+        let all: Vec<_> = g.symmetries().collect();
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[0], g);
+        for (i, a) in all.iter().enumerate() {
+            for b in &all[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn dense_keeps_its_shape() {
+        let g = DenseGrid::from_str_with(L, |c| (c == '#').then_some(c));
+        let r = g.rotate_cw();
+        assert_eq!(r.visualise(), "###\n#..\n...\n");
+        assert_eq!(r.width(), 3);
+        assert_eq!(g.transpose().visualise(), "###\n..#\n...\n");
+    }
+
+    #[test]
+    fn crop_and_pad() {
+        let g = parse(L);
+        let c = g.crop(0..=1, 2..=2);
+        assert_eq!(c.visualise(), "##\n");
+
+        let p = c.pad(1, '+');
+        assert_eq!(p.visualise(), "++++\n+##+\n++++\n");
+
+        let d = DenseGrid::from_str_with(L, |c| (c == '#').then_some(c)).pad(1, '+');
+        assert_eq!(d.bounds(), [-1..=3, -1..=3]);
+        assert_eq!(d.visualise(), "+++++\n+#..+\n+#..+\n+##.+\n+++++\n");
+    }
+}
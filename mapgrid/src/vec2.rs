@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Coord;
+
+/// A 2D integer vector, for when `Coord` needs arithmetic.
+///
+/// Converts to and from `Coord` with `From`/`Into`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Length in steps along the axes: |x| + |y|
+    pub fn manhattan(self) -> isize {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Length in king's moves: max(|x|, |y|)
+    pub fn chebyshev(self) -> isize {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn dot(self, other: Vec2) -> isize {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product, i.e. the determinant of `[self other]`.
+    ///
+    /// Positive when `other` is clockwise of `self` (in screen coordinates, +Y south).
+    pub fn cross(self, other: Vec2) -> isize {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Each component's sign: a unit step (possibly diagonal) in roughly this direction
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Elementwise, so always within `[0, 0]..rhs`
+    pub fn rem_euclid(self, rhs: Vec2) -> Self {
+        Self::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+impl From<Coord> for Vec2 {
+    fn from([x, y]: Coord) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Vec2> for Coord {
+    fn from(v: Vec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Mul<Vec2> for isize {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        rhs * self
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b: Vec2 = [1, 2].into();
+
+        assert_eq!(a + b, Vec2::new(4, -2));
+        assert_eq!(a - b, Vec2::new(2, -6));
+        assert_eq!(a * 2, 2 * a);
+        assert_eq!(-a, Vec2::new(-3, 4));
+        assert_eq!(Coord::from(a), [3, -4]);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        assert_eq!(a.manhattan(), 7);
+        assert_eq!(a.chebyshev(), 4);
+        assert_eq!(a.dot(b), -5);
+        assert_eq!(a.cross(b), 10);
+        assert_eq!(a.signum(), Vec2::new(1, -1));
+        assert_eq!(a.rem_euclid(Vec2::new(2, 3)), Vec2::new(1, 2));
+        assert_eq!(a.to_string(), "[3, -4]");
+    }
+
+    #[test]
+    fn cramer() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let (a, b, p) = (Vec2::new(94, 34), Vec2::new(22, 67), Vec2::new(8400, 5400));
+        let det = a.cross(b);
+        let (x, y) = (p.cross(b) / det, a.cross(p) / det);
+        assert_eq!((x, y), (80, 40));
+        assert_eq!(a * x + b * y, p);
+    }
+}
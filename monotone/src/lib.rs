@@ -0,0 +1,323 @@
+//! Finding where a monotone predicate starts to hold.
+//!
+//! Plenty of puzzles are "the smallest N such that ...", where once it's true
+//! it stays true: how many bytes until the exit is cut off, how big a number
+//! until something overflows. Rather than trying every N, search for the boundary.
+//!
+//! Predicates return `Some(witness)` where they hold, so you get back whatever
+//! was worked out at the boundary as well as where it is.
+//!
+//! Not every "first N" is a boundary, though. Day 14's tree is there for one second
+//! and gone the next, so there's nothing to bisect, and it still scans.
+
+use std::{error::Error, fmt, ops::Range};
+
+use rayon::prelude::*;
+
+/// The smallest point where the predicate held, and what it returned there
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Found<T> {
+    pub at: usize,
+    pub witness: T,
+}
+
+/// Sampling found the predicate holding at one point and failing at a later one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotMonotone {
+    pub holds: usize,
+    pub fails: usize,
+}
+
+impl fmt::Display for NotMonotone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "predicate is not monotone: holds at {} but not at {}",
+            self.holds, self.fails
+        )
+    }
+}
+
+impl Error for NotMonotone {}
+
+/// How to search: one probe at a time or several in parallel,
+/// and whether to check the predicate really is monotone first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Search {
+    probes: usize,
+    verify: usize,
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self {
+            probes: 1,
+            verify: 0,
+        }
+    }
+}
+
+impl Search {
+    /// Plain binary search, without verification
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluate this many evenly spaced points per round, in parallel.
+    ///
+    /// Worth it when the predicate is expensive; `1` is plain binary search.
+    pub fn parallel(mut self, probes: usize) -> Self {
+        self.probes = probes.max(1);
+        self
+    }
+
+    /// Before searching, check the predicate on this many sampled points
+    /// (the ends included), and panic if it isn't monotone there.
+    ///
+    /// Handy in tests and debug builds; `0` turns it off.
+    pub fn verify(mut self, samples: usize) -> Self {
+        self.verify = samples;
+        self
+    }
+
+    /// The smallest point in `range` where `pred` holds, if it holds anywhere
+    pub fn first<T, F>(&self, range: Range<usize>, pred: F) -> Option<Found<T>>
+    where
+        F: Fn(usize) -> Option<T> + Sync,
+        T: Send,
+    {
+        if self.verify > 0 {
+            if let Err(e) = check_monotone(range.clone(), self.verify, &pred) {
+                panic!("{e}");
+            }
+        }
+
+        let (mut lo, mut hi) = (range.start, range.end);
+        let mut best = None;
+
+        while lo < hi {
+            let n = hi - lo;
+            let mut points: Vec<usize> = (1..=self.probes)
+                .map(|i| lo + fraction(n, i, self.probes + 1))
+                .collect();
+            points.dedup();
+
+            let results = self.evaluate(&points, &pred);
+            match results
+                .into_iter()
+                .enumerate()
+                .find_map(|(j, r)| r.map(|w| (j, w)))
+            {
+                Some((j, witness)) => {
+                    hi = points[j];
+                    lo = if j > 0 { points[j - 1] + 1 } else { lo };
+                    best = Some(Found { at: hi, witness });
+                }
+                None => lo = points[points.len() - 1] + 1,
+            }
+        }
+        best
+    }
+
+    /// Like `first`, but for when there's no known upper bound:
+    /// probe `start`, `start + 1`, `start + 3`, `start + 7`, ... until `pred` holds,
+    /// then search the last gap.
+    ///
+    /// `None` only if `pred` never holds before `usize` runs out.
+    pub fn gallop<T, F>(&self, start: usize, pred: F) -> Option<Found<T>>
+    where
+        F: Fn(usize) -> Option<T> + Sync,
+        T: Send,
+    {
+        let mut prev = start;
+        let mut probe = start;
+        let mut step = 1_usize;
+        loop {
+            if let Some(witness) = pred(probe) {
+                if probe == start {
+                    return Some(Found { at: probe, witness });
+                }
+                if self.verify > 0 {
+                    if let Err(e) = check_monotone(start..probe + 1, self.verify, &pred) {
+                        panic!("{e}");
+                    }
+                }
+                let inner = Search { verify: 0, ..*self };
+                return inner
+                    .first(prev + 1..probe, &pred)
+                    .or(Some(Found { at: probe, witness }));
+            }
+            prev = probe;
+            probe = start.checked_add(step)?;
+            step = step.checked_mul(2)?.checked_add(1)?;
+        }
+    }
+
+    fn evaluate<T, F>(&self, points: &[usize], pred: &F) -> Vec<Option<T>>
+    where
+        F: Fn(usize) -> Option<T> + Sync,
+        T: Send,
+    {
+        if self.probes > 1 {
+            points.par_iter().map(|&p| pred(p)).collect()
+        } else {
+            points.iter().map(|&p| pred(p)).collect()
+        }
+    }
+}
+
+/// `Search::new().first(range, pred)`
+pub fn first<T, F>(range: Range<usize>, pred: F) -> Option<Found<T>>
+where
+    F: Fn(usize) -> Option<T> + Sync,
+    T: Send,
+{
+    Search::new().first(range, pred)
+}
+
+/// `Search::new().gallop(start, pred)`
+pub fn gallop<T, F>(start: usize, pred: F) -> Option<Found<T>>
+where
+    F: Fn(usize) -> Option<T> + Sync,
+    T: Send,
+{
+    Search::new().gallop(start, pred)
+}
+
+/// Evaluate `pred` at `samples` evenly spaced points of `range` (the ends included)
+/// and check it never goes from holding to not holding.
+pub fn check_monotone<T, F>(range: Range<usize>, samples: usize, pred: F) -> Result<(), NotMonotone>
+where
+    F: Fn(usize) -> Option<T> + Sync,
+    T: Send,
+{
+    if range.is_empty() || samples == 0 {
+        return Ok(());
+    }
+    let last = range.end - 1;
+    let span = last - range.start;
+    let mut points: Vec<usize> = if samples == 1 {
+        vec![last]
+    } else {
+        (0..samples)
+            .map(|i| range.start + fraction(span, i, samples - 1))
+            .collect()
+    };
+    points.dedup();
+
+    let holds: Vec<bool> = points.par_iter().map(|&p| pred(p).is_some()).collect();
+    match holds.iter().position(|h| *h) {
+        Some(i) => match holds[i..].iter().position(|h| !h) {
+            Some(j) => Err(NotMonotone {
+                holds: points[i],
+                fails: points[i + j],
+            }),
+            None => Ok(()),
+        },
+        None => Ok(()),
+    }
+}
+
+/// `n * i / parts`, without overflowing
+fn fraction(n: usize, i: usize, parts: usize) -> usize {
+    (n as u128 * i as u128 / parts as u128) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn binary() {
+        let found = first(0..1000, |n| (n * n >= 500).then_some(n * n));
+        assert_eq!(
+            found,
+            Some(Found {
+                at: 23,
+                witness: 529
+            })
+        );
+
+        assert_eq!(first(0..10, |_| Some(())).map(|f| f.at), Some(0));
+        assert_eq!(
+            first(0..10, |n| (n >= 9).then_some(())).map(|f| f.at),
+            Some(9)
+        );
+        assert_eq!(first(0..10, |n| (n >= 10).then_some(())), None);
+        assert_eq!(first(5..5, |_| Some(())), None);
+    }
+
+    #[test]
+    fn few_calls() {
+        let calls = AtomicUsize::new(0);
+        let found = first(0..1 << 20, |n| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            (n >= 123_456).then_some(())
+        });
+        assert_eq!(found.map(|f| f.at), Some(123_456));
+        assert!(calls.into_inner() <= 21);
+    }
+
+    #[test]
+    fn parallel() {
+        for probes in [2, 3, 8, 100] {
+            let search = Search::new().parallel(probes);
+            for target in [0, 1, 17, 998, 999] {
+                let found = search.first(0..1000, |n| (n >= target).then_some(n));
+                assert_eq!(
+                    found,
+                    Some(Found {
+                        at: target,
+                        witness: target
+                    })
+                );
+            }
+            assert_eq!(search.first(0..1000, |n| (n >= 1000).then_some(())), None);
+        }
+    }
+
+    #[test]
+    fn galloping() {
+        let found = gallop(10, |n| (n >= 1_000_000).then(|| n - 1_000_000));
+        assert_eq!(
+            found,
+            Some(Found {
+                at: 1_000_000,
+                witness: 0
+            })
+        );
+        assert_eq!(gallop(7, |n| (n >= 3).then_some(())).map(|f| f.at), Some(7));
+        assert_eq!(gallop(0, |n| (n >= 1).then_some(())).map(|f| f.at), Some(1));
+        assert_eq!(gallop(0, |_| None::<()>), None);
+    }
+
+    #[test]
+    fn verification() {
+        let bumpy = |n: usize| (n % 100 < 50).then_some(());
+        assert_eq!(
+            check_monotone(0..1000, 11, bumpy),
+            Err(NotMonotone {
+                holds: 0,
+                fails: 99
+            })
+        );
+        assert_eq!(
+            check_monotone(0..1000, 11, |n| (n > 500).then_some(())),
+            Ok(())
+        );
+
+        let found = Search::new()
+            .verify(20)
+            .first(0..1000, |n| (n >= 400).then_some(()));
+        assert_eq!(found.map(|f| f.at), Some(400));
+    }
+
+    #[test]
+    #[should_panic(expected = "not monotone")]
+    fn verification_fails() {
+        Search::new()
+            .verify(11)
+            .first(0..1000, |n| (n % 100 < 50).then_some(()));
+    }
+}
@@ -10,15 +10,11 @@ cp -R template "$newday"
 
 sed -i '.bak' -e "s/template/$newday/" "$newday/Cargo.toml"
 
-printf -v newdayversion -- 's/^version =.*$/version = "0.%d.0"/' "$daynum"
+sed -i '.bak' -e "s/template::/${newday//-/_}::/" "$newday/src/main.rs"
 
-printf -v newtoday -- 's|^path = "day-.*$|path = "%s/src/main.rs"|' "$newday"
+rm "$newday/Cargo.toml.bak" "$newday/src/main.rs.bak"
 
-sed -i '.bak' -e "$newdayversion" -e "$newtoday" Cargo.toml
-
-rm "Cargo.toml.bak" "$newday/Cargo.toml.bak"
-
-git add "Cargo.toml" "$newday"
+git add "$newday"
 git commit -am "day-$daynum"
 
 curl "https://adventofcode.com/2024/day/$daynum/input" --header "Cookie: session=$(cat .token)" > "$newday/input.txt"
@@ -0,0 +1,177 @@
+//! Run any day, or all of them, without `cd`-ing into its crate.
+//!
+//! `aoc run 7`, `aoc run 7 --part 2`, `aoc run all`, `aoc run latest`.
+//! Input is read from `day-NN/input.txt` in the workspace unless another file is given.
+//!
+//! Every `day-NN` directory in the workspace is a day, so a new one needs no registering:
+//! each is built with `cargo build` and run as its own binary.
+
+use std::{
+    env::{self, consts::EXE_SUFFIX},
+    ffi::OsString,
+    fs::read_dir,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command as Process,
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+use aoc_common::{init_logger, InfoLevel, Verbosity};
+use clap::{Parser, Subcommand};
+use log::{warn, LevelFilter};
+
+#[derive(Parser)]
+pub struct Opts {
+    /// Tell me more (or less)
+    #[clap(flatten)]
+    verbose: Verbosity<InfoLevel>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle
+    Run {
+        /// A day number, `latest`, or `all`
+        day: Which,
+        /// Only this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin (default: day-NN/input.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Which {
+    Day(u32),
+    Latest,
+    All,
+}
+
+impl FromStr for Which {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(Which::Latest),
+            "all" => Ok(Which::All),
+            _ => s
+                .parse()
+                .map(Which::Day)
+                .map_err(|_| format!("expected a day number, `latest` or `all`, not {s:?}")),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let opts: Opts = clap::Parser::parse();
+    let level = opts.verbose.log_level_filter();
+    init_logger(level);
+
+    let Command::Run { day, part, input } = opts.command;
+
+    let all = days()?;
+    let days: Vec<u32> = match day {
+        Which::All => all,
+        Which::Latest => all.last().copied().into_iter().collect(),
+        Which::Day(n) => all.into_iter().filter(|d| *d == n).collect(),
+    };
+    match (day, days.is_empty()) {
+        (Which::Day(n), true) => bail!("no solution for day {n}"),
+        (_, true) => bail!("no days to run"),
+        _ => (),
+    }
+    if input.is_some() && days.len() > 1 {
+        bail!("--input only makes sense for a single day");
+    }
+
+    for n in days {
+        let path = input.clone().unwrap_or_else(|| default_input(n));
+        if day == Which::All && !path.exists() {
+            warn!("Skipping day {n}: no {}", path.display());
+            continue;
+        }
+        println!("Day {n}");
+        solve(n, &path, part, level)?;
+    }
+    Ok(())
+}
+
+/// The workspace, wherever we're run from
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Every `day-NN` crate in the workspace, in order
+fn days() -> Result<Vec<u32>> {
+    let mut out = vec![];
+    for entry in read_dir(root())? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(n) = name.to_str().and_then(|s| s.strip_prefix("day-")) else {
+            continue;
+        };
+        if let Ok(n) = n.parse() {
+            if entry.path().join("Cargo.toml").exists() {
+                out.push(n);
+            }
+        }
+    }
+    out.sort();
+    Ok(out)
+}
+
+/// `day-NN/input.txt` in the workspace
+fn default_input(day: u32) -> PathBuf {
+    root().join(format!("day-{day:02}/input.txt"))
+}
+
+/// The flag that gives a day's binary the same log level as ours
+fn verbosity_flag(level: LevelFilter) -> Option<&'static str> {
+    match level {
+        LevelFilter::Off => Some("-qqq"),
+        LevelFilter::Error => Some("-qq"),
+        LevelFilter::Warn => Some("-q"),
+        LevelFilter::Info => None,
+        LevelFilter::Debug => Some("-v"),
+        LevelFilter::Trace => Some("-vv"),
+    }
+}
+
+/// Build and run a day's binary, in the same profile as this one
+fn solve(day: u32, path: &Path, part: Option<u8>, level: LevelFilter) -> Result<()> {
+    let package = format!("day-{day:02}");
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut build = Process::new(cargo);
+    build
+        .current_dir(root())
+        .args(["build", "--quiet", "--package", &package]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    // a day's compiler warnings are for `cargo build`, not for every run
+    let built = build.output()?;
+    if !built.status.success() {
+        io::stderr().write_all(&built.stderr)?;
+        bail!("day {day} doesn't build");
+    }
+
+    // it lands next to us, in the workspace's target directory
+    let bin = env::current_exe()?.with_file_name(format!("{package}{EXE_SUFFIX}"));
+    let mut cmd = Process::new(bin);
+    cmd.args(verbosity_flag(level));
+    if let Some(p) = part {
+        cmd.args(["--part", &p.to_string()]);
+    }
+    cmd.arg(path);
+
+    let status = cmd.status()?;
+    if !status.success() {
+        bail!("day {day} failed ({status})");
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use aoc_common::{Answer, Solution};
+use itertools::Itertools;
+use log::{debug, info, warn};
+use mapgrid::*;
+use nom;
+use regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use strum;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part_1(input: &str) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &str) -> Answer {
+        part_2(input).into()
+    }
+}
+
+fn part_1(infile: &str) -> usize {
+    todo!()
+}
+fn part_2(infile: &str) -> usize {
+    todo!()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = r"
+";
+
+    #[test]
+    fn part_1_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Trace);
+        assert_eq!(part_1(EXAMPLE_1), todo!());
+    }
+
+    #[test]
+    fn part_2_example() {
+        aoc_common::init_test_logger(log::LevelFilter::Trace);
+        assert_eq!(part_2(EXAMPLE_1), todo!());
+    }
+}